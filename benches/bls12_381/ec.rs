@@ -1,8 +1,9 @@
 mod g1 {
     use rand::{Rand, SeedableRng, XorShiftRng};
 
-    use pairing_ce::bls12_381::*;
-    use pairing_ce::CurveProjective;
+    use ff::PrimeField;
+    use pairing_ce::engines::bls12_381::*;
+    use pairing_ce::{CurveAffine, CurveProjective, EncodedPoint};
 
     #[bench]
     fn bench_g1_mul_assign(b: &mut ::test::Bencher) {
@@ -60,13 +61,70 @@ mod g1 {
             tmp
         });
     }
+
+    #[bench]
+    fn bench_g1_decompress(b: &mut ::test::Bencher) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let v: Vec<G1Compressed> = (0..SAMPLES)
+            .map(|_| G1::rand(&mut rng).into_affine().into_compressed())
+            .collect();
+
+        let mut count = 0;
+        b.iter(|| {
+            let tmp = v[count].into_affine().unwrap();
+            count = (count + 1) % SAMPLES;
+            tmp
+        });
+    }
+
+    #[bench]
+    fn bench_g1_decompress_unchecked(b: &mut ::test::Bencher) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let v: Vec<G1Compressed> = (0..SAMPLES)
+            .map(|_| G1::rand(&mut rng).into_affine().into_compressed())
+            .collect();
+
+        let mut count = 0;
+        b.iter(|| {
+            let tmp = v[count].into_affine_unchecked().unwrap();
+            count = (count + 1) % SAMPLES;
+            tmp
+        });
+    }
+
+    // The subgroup check by multiplication with the group order, which the
+    // decoders used before the endomorphism-based checks.
+    #[bench]
+    fn bench_g1_subgroup_check_by_order(b: &mut ::test::Bencher) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let v: Vec<G1Affine> = (0..SAMPLES)
+            .map(|_| G1::rand(&mut rng).into_affine())
+            .collect();
+
+        let mut count = 0;
+        b.iter(|| {
+            let tmp = v[count].mul(Fr::char()).is_zero();
+            count = (count + 1) % SAMPLES;
+            tmp
+        });
+    }
 }
 
 mod g2 {
     use rand::{Rand, SeedableRng, XorShiftRng};
 
-    use pairing_ce::bls12_381::*;
-    use pairing_ce::CurveProjective;
+    use ff::PrimeField;
+    use pairing_ce::engines::bls12_381::*;
+    use pairing_ce::{CurveAffine, CurveProjective, EncodedPoint};
 
     #[bench]
     fn bench_g2_mul_assign(b: &mut ::test::Bencher) {
@@ -87,6 +145,24 @@ mod g2 {
         });
     }
 
+    #[bench]
+    fn bench_g2_mul_gls(b: &mut ::test::Bencher) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let v: Vec<(G2, Fr)> = (0..SAMPLES)
+            .map(|_| (G2::rand(&mut rng), Fr::rand(&mut rng)))
+            .collect();
+
+        let mut count = 0;
+        b.iter(|| {
+            let tmp = v[count].0.mul_gls(v[count].1);
+            count = (count + 1) % SAMPLES;
+            tmp
+        });
+    }
+
     #[bench]
     fn bench_g2_add_assign(b: &mut ::test::Bencher) {
         const SAMPLES: usize = 1000;
@@ -124,4 +200,60 @@ mod g2 {
             tmp
         });
     }
+
+    #[bench]
+    fn bench_g2_decompress(b: &mut ::test::Bencher) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let v: Vec<G2Compressed> = (0..SAMPLES)
+            .map(|_| G2::rand(&mut rng).into_affine().into_compressed())
+            .collect();
+
+        let mut count = 0;
+        b.iter(|| {
+            let tmp = v[count].into_affine().unwrap();
+            count = (count + 1) % SAMPLES;
+            tmp
+        });
+    }
+
+    #[bench]
+    fn bench_g2_decompress_unchecked(b: &mut ::test::Bencher) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let v: Vec<G2Compressed> = (0..SAMPLES)
+            .map(|_| G2::rand(&mut rng).into_affine().into_compressed())
+            .collect();
+
+        let mut count = 0;
+        b.iter(|| {
+            let tmp = v[count].into_affine_unchecked().unwrap();
+            count = (count + 1) % SAMPLES;
+            tmp
+        });
+    }
+
+    // The subgroup check by multiplication with the group order, which the
+    // decoders used before the endomorphism-based checks.
+    #[bench]
+    fn bench_g2_subgroup_check_by_order(b: &mut ::test::Bencher) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let v: Vec<G2Affine> = (0..SAMPLES)
+            .map(|_| G2::rand(&mut rng).into_affine())
+            .collect();
+
+        let mut count = 0;
+        b.iter(|| {
+            let tmp = v[count].mul(Fr::char()).is_zero();
+            count = (count + 1) % SAMPLES;
+            tmp
+        });
+    }
 }
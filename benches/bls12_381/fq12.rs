@@ -1,7 +1,7 @@
 use rand::{Rand, SeedableRng, XorShiftRng};
 
 use ff::Field;
-use pairing_ce::bls12_381::*;
+use pairing_ce::engines::bls12_381::*;
 
 #[bench]
 fn bench_fq12_add_assign(b: &mut ::test::Bencher) {
@@ -77,6 +77,42 @@ fn bench_fq12_squaring(b: &mut ::test::Bencher) {
     });
 }
 
+#[bench]
+fn bench_fq12_cyclotomic_squaring(b: &mut ::test::Bencher) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    let v: Vec<Fq12> = (0..SAMPLES).map(|_| Fq12::rand(&mut rng)).collect();
+
+    let mut count = 0;
+    b.iter(|| {
+        let mut tmp = v[count];
+        tmp.cyclotomic_square();
+        count = (count + 1) % SAMPLES;
+        tmp
+    });
+}
+
+#[bench]
+fn bench_fq12_compressed_squaring(b: &mut ::test::Bencher) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    let v: Vec<CompressedFq12> = (0..SAMPLES)
+        .map(|_| CompressedFq12::compress(&Fq12::rand(&mut rng)).unwrap())
+        .collect();
+
+    let mut count = 0;
+    b.iter(|| {
+        let mut tmp = v[count];
+        tmp.square();
+        count = (count + 1) % SAMPLES;
+        tmp
+    });
+}
+
 #[bench]
 fn bench_fq12_inverse(b: &mut ::test::Bencher) {
     const SAMPLES: usize = 1000;
@@ -6,7 +6,7 @@ mod fr;
 
 use rand::{Rand, SeedableRng, XorShiftRng};
 
-use pairing_ce::bls12_381::*;
+use pairing_ce::engines::bls12_381::*;
 use pairing_ce::{CurveAffine, Engine};
 
 #[bench]
@@ -1,7 +1,7 @@
 mod g1 {
     use rand::{Rand, SeedableRng, XorShiftRng};
 
-    use pairing_ce::bn256::*;
+    use pairing_ce::engines::bn256::*;
     use pairing_ce::CurveProjective;
 
     #[bench]
@@ -60,12 +60,31 @@ mod g1 {
             tmp
         });
     }
+
+    #[bench]
+    fn bench_g1_multiexp(b: &mut ::test::Bencher) {
+        use ff::PrimeField;
+        use pairing_ce::multiexp;
+
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let bases: Vec<G1Affine> = (0..SAMPLES)
+            .map(|_| G1::rand(&mut rng).into_affine())
+            .collect();
+        let scalars: Vec<FrRepr> = (0..SAMPLES)
+            .map(|_| Fr::rand(&mut rng).into_repr())
+            .collect();
+
+        b.iter(|| multiexp(&bases, &scalars));
+    }
 }
 
 mod g2 {
     use rand::{Rand, SeedableRng, XorShiftRng};
 
-    use pairing_ce::bls12_381::*;
+    use pairing_ce::engines::bn256::*;
     use pairing_ce::CurveProjective;
 
     #[bench]
@@ -87,6 +106,24 @@ mod g2 {
         });
     }
 
+    #[bench]
+    fn bench_g2_mul_gls(b: &mut ::test::Bencher) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let v: Vec<(G2, Fr)> = (0..SAMPLES)
+            .map(|_| (G2::rand(&mut rng), Fr::rand(&mut rng)))
+            .collect();
+
+        let mut count = 0;
+        b.iter(|| {
+            let tmp = v[count].0.mul_gls(v[count].1);
+            count = (count + 1) % SAMPLES;
+            tmp
+        });
+    }
+
     #[bench]
     fn bench_g2_add_assign(b: &mut ::test::Bencher) {
         const SAMPLES: usize = 1000;
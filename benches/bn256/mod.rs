@@ -6,7 +6,7 @@ mod fr;
 
 use rand::{Rand, SeedableRng, XorShiftRng};
 
-use pairing_ce::bn256::*;
+use pairing_ce::engines::bn256::*;
 use pairing_ce::{CurveAffine, Engine};
 
 #[bench]
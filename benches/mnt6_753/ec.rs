@@ -1,7 +1,7 @@
 mod g1 {
     use rand::{Rand, SeedableRng, XorShiftRng};
 
-    use pairing_ce::bn256::*;
+    use pairing_ce::engines::bn256::*;
     use pairing_ce::CurveProjective;
 
     #[bench]
@@ -65,7 +65,7 @@ mod g1 {
 mod g2 {
     use rand::{Rand, SeedableRng, XorShiftRng};
 
-    use pairing_ce::bls12_381::*;
+    use pairing_ce::engines::bls12_381::*;
     use pairing_ce::CurveProjective;
 
     #[bench]
@@ -1,7 +1,7 @@
 use rand::{Rand, SeedableRng, XorShiftRng};
 
 use ff::Field;
-use pairing_ce::mnt6_753::*;
+use pairing_ce::engines::mnt6_753::*;
 
 #[bench]
 fn bench_fq6_add_assign(b: &mut ::test::Bencher) {
@@ -6,7 +6,7 @@ mod fr;
 
 use rand::{Rand, SeedableRng, XorShiftRng};
 
-use pairing_ce::bn256::*;
+use pairing_ce::engines::mnt6_753::*;
 use pairing_ce::{CurveAffine, Engine};
 
 #[bench]
@@ -58,7 +58,7 @@ fn bench_pairing_miller_loop(b: &mut ::test::Bencher) {
 
     let mut count = 0;
     b.iter(|| {
-        let tmp = Bn256::miller_loop(&[(&v[count].0, &v[count].1)]);
+        let tmp = Mnt6::miller_loop(&[(&v[count].0, &v[count].1)]);
         count = (count + 1) % SAMPLES;
         tmp
     });
@@ -70,19 +70,19 @@ fn bench_pairing_final_exponentiation(b: &mut ::test::Bencher) {
 
     let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
 
-    let v: Vec<Fq12> = (0..SAMPLES)
+    let v: Vec<Fq6> = (0..SAMPLES)
         .map(|_| {
             (
                 G1Affine::from(G1::rand(&mut rng)).prepare(),
                 G2Affine::from(G2::rand(&mut rng)).prepare(),
             )
         })
-        .map(|(ref p, ref q)| Bn256::miller_loop(&[(p, q)]))
+        .map(|(ref p, ref q)| Mnt6::miller_loop(&[(p, q)]))
         .collect();
 
     let mut count = 0;
     b.iter(|| {
-        let tmp = Bn256::final_exponentiation(&v[count]);
+        let tmp = Mnt6::final_exponentiation(&v[count]);
         count = (count + 1) % SAMPLES;
         tmp
     });
@@ -100,7 +100,7 @@ fn bench_pairing_full(b: &mut ::test::Bencher) {
 
     let mut count = 0;
     b.iter(|| {
-        let tmp = Bn256::pairing(v[count].0, v[count].1);
+        let tmp = Mnt6::pairing(v[count].0, v[count].1);
         count = (count + 1) % SAMPLES;
         tmp
     });
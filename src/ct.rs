@@ -0,0 +1,308 @@
+//! Constant-time operations, for computations on secret data.
+//!
+//! The selections, comparisons and negations here do not branch on the
+//! values they work on, and scalar multiplication uses a fixed window of
+//! signed digits with table lookups that touch every entry, so that its
+//! running time and memory accesses do not depend on the scalar. The field
+//! arithmetic underneath comes from `ff`, whose Montgomery reduction ends with
+//! a conditional subtraction; these operations remove the data-dependent
+//! branches of this crate, not those of `ff`.
+//!
+//! `CurveAffine::mul` and wNAF remain the faster choice for public data.
+
+use ff::{Field, PrimeField, PrimeFieldRepr};
+use zeroize::Zeroize;
+
+use crate::{CurveAffine, CurveProjective};
+
+pub use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// Constant-time operations on field elements.
+pub trait CtField: Field + ConditionallySelectable + ConstantTimeEq {
+    /// Negates this element if `choice` is set.
+    fn conditional_negate(&mut self, choice: Choice) {
+        let mut neg = *self;
+        neg.negate();
+        self.conditional_assign(&neg, choice);
+    }
+
+    /// Computes the multiplicative inverse of this element, if nonzero, as a
+    /// power with a fixed exponent.
+    fn ct_inverse(&self) -> CtOption<Self>;
+}
+
+/// Constant-time operations on affine points.
+pub trait CtCurveAffine: CurveAffine + ConditionallySelectable + ConstantTimeEq {
+    /// Negates this point if `choice` is set.
+    fn conditional_negate(&mut self, choice: Choice);
+
+    /// Multiplies this point by a secret scalar in constant time, returning
+    /// the result in affine form.
+    fn ct_mul<S: Into<<Self::Scalar as PrimeField>::Repr>>(&self, scalar: S) -> Self;
+}
+
+/// Constant-time operations on projective points.
+pub trait CtCurveProjective: CurveProjective + ConditionallySelectable + ConstantTimeEq {
+    /// Negates this point if `choice` is set.
+    fn conditional_negate(&mut self, choice: Choice);
+
+    /// Converts this point into affine form with a constant-time inversion.
+    fn ct_into_affine(&self) -> Self::Affine;
+}
+
+/// Implements the constant-time traits, and `Zeroize`, for a prime field
+/// `$field` defined in the invoking module as a wrapper of `$repr`.
+macro_rules! ct_prime_field_impl {
+    ($field:ident, $repr:ident) => {
+        impl ::subtle::ConditionallySelectable for $field {
+            fn conditional_select(a: &Self, b: &Self, choice: ::subtle::Choice) -> Self {
+                let mut limbs = (a.0).0;
+                for (a, b) in limbs.iter_mut().zip((b.0).0.iter()) {
+                    *a = ::subtle::ConditionallySelectable::conditional_select(a, b, choice);
+                }
+
+                $field($repr(limbs))
+            }
+        }
+
+        impl ::subtle::ConstantTimeEq for $field {
+            fn ct_eq(&self, other: &Self) -> ::subtle::Choice {
+                ::subtle::ConstantTimeEq::ct_eq(&(self.0).0[..], &(other.0).0[..])
+            }
+        }
+
+        impl $crate::ct::CtField for $field {
+            fn ct_inverse(&self) -> ::subtle::CtOption<Self> {
+                let q = <Self as ::ff::PrimeField>::char();
+                let inverse = $crate::ct::fermat_inverse(self, q.as_ref(), 1);
+                let is_zero = ::subtle::ConstantTimeEq::ct_eq(self, &<Self as ::ff::Field>::zero());
+
+                ::subtle::CtOption::new(inverse, !is_zero)
+            }
+        }
+
+        impl ::zeroize::Zeroize for $field {
+            fn zeroize(&mut self) {
+                ::zeroize::Zeroize::zeroize(&mut (self.0).0);
+            }
+        }
+    };
+}
+
+/// Returns `a^(q^degree - 2)`, the inverse of `a` in a field of `q^degree`
+/// elements when `a` is nonzero, where `q` is given by its little-endian
+/// limbs. The exponent is public, so the square-and-multiply of `pow` does not
+/// depend on `a`.
+pub(crate) fn fermat_inverse<F: Field>(a: &F, q: &[u64], degree: usize) -> F {
+    let mut exponent = vec![1u64];
+    for _ in 0..degree {
+        let mut product = vec![0u64; exponent.len() + q.len()];
+        for (i, x) in exponent.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, y) in q.iter().enumerate() {
+                let t = (*x as u128) * (*y as u128) + (product[i + j] as u128) + carry;
+                product[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            product[i + q.len()] = carry as u64;
+        }
+        exponent = product;
+    }
+
+    // q^degree is odd, so subtracting two only borrows from the lowest limb
+    // when it is one
+    let mut borrow = 2;
+    for limb in exponent.iter_mut() {
+        let (l, b) = limb.overflowing_sub(borrow);
+        *limb = l;
+        borrow = b as u64;
+    }
+
+    a.pow(&exponent)
+}
+
+/// A point in homogeneous projective coordinates `(X : Y : Z)`, standing for
+/// `(X / Z, Y / Z)`, with `(0 : 1 : 0)` the point at infinity.
+#[derive(Copy, Clone)]
+struct Homogeneous<F> {
+    x: F,
+    y: F,
+    z: F,
+}
+
+impl<F: CtField> ConditionallySelectable for Homogeneous<F> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Homogeneous {
+            x: F::conditional_select(&a.x, &b.x, choice),
+            y: F::conditional_select(&a.y, &b.y, choice),
+            z: F::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+impl<F: CtField> Homogeneous<F> {
+    fn zero() -> Self {
+        Homogeneous {
+            x: F::zero(),
+            y: F::one(),
+            z: F::zero(),
+        }
+    }
+
+    /// Adds `other` to this point with the complete formulas of Algorithm 1
+    /// of Renes, Costello and Batina, https://eprint.iacr.org/2015/1060.pdf,
+    /// for `y^2 = x^3 + a * x + b`, where `b3 = 3 * b`. They only fail when
+    /// the difference of the points has order two, which never happens in
+    /// groups of odd order.
+    fn add(&self, other: &Self, a: &F, b3: &F) -> Self {
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let mut t0 = *x1;
+        t0.mul_assign(x2);
+        let mut t1 = *y1;
+        t1.mul_assign(y2);
+        let mut t2 = *z1;
+        t2.mul_assign(z2);
+
+        let mut t3 = *x1;
+        t3.add_assign(y1);
+        let mut t4 = *x2;
+        t4.add_assign(y2);
+        t3.mul_assign(&t4);
+        t4 = t0;
+        t4.add_assign(&t1);
+        t3.sub_assign(&t4);
+
+        t4 = *x1;
+        t4.add_assign(z1);
+        let mut t5 = *x2;
+        t5.add_assign(z2);
+        t4.mul_assign(&t5);
+        t5 = t0;
+        t5.add_assign(&t2);
+        t4.sub_assign(&t5);
+
+        t5 = *y1;
+        t5.add_assign(z1);
+        let mut x3 = *y2;
+        x3.add_assign(z2);
+        t5.mul_assign(&x3);
+        x3 = t1;
+        x3.add_assign(&t2);
+        t5.sub_assign(&x3);
+
+        let mut z3 = *a;
+        z3.mul_assign(&t4);
+        x3 = *b3;
+        x3.mul_assign(&t2);
+        z3.add_assign(&x3);
+        x3 = t1;
+        x3.sub_assign(&z3);
+        z3.add_assign(&t1);
+        let mut y3 = x3;
+        y3.mul_assign(&z3);
+
+        t1 = t0;
+        t1.double();
+        t1.add_assign(&t0);
+        t2.mul_assign(a);
+        t4.mul_assign(b3);
+        t1.add_assign(&t2);
+        t2.negate();
+        t2.add_assign(&t0);
+        t2.mul_assign(a);
+        t4.add_assign(&t2);
+
+        t0 = t1;
+        t0.mul_assign(&t4);
+        y3.add_assign(&t0);
+        t0 = t5;
+        t0.mul_assign(&t4);
+        x3.mul_assign(&t3);
+        x3.sub_assign(&t0);
+        t0 = t3;
+        t0.mul_assign(&t1);
+        z3.mul_assign(&t5);
+        z3.add_assign(&t0);
+
+        Homogeneous { x: x3, y: y3, z: z3 }
+    }
+}
+
+/// The width of the windows of `mul`.
+const WINDOW: usize = 4;
+
+/// Computes `scalar * (x, y)` on `y^2 = x^3 + a * x + b`, or on its point at
+/// infinity if `infinity` is set, in a prime order subgroup, and returns the
+/// affine coordinates of the result and whether it is the point at infinity.
+///
+/// The scalar is recoded into digits in `[-2^(WINDOW - 1), 2^(WINDOW - 1))`,
+/// one for every `WINDOW` bits of the repr and one more for the final carry,
+/// so the number of operations only depends on the size of the repr. The
+/// scalar and its digits are zeroized before returning.
+pub(crate) fn mul<F: CtField, R: PrimeFieldRepr>(
+    x: &F,
+    y: &F,
+    infinity: Choice,
+    a: &F,
+    b: &F,
+    mut scalar: R,
+) -> (F, F, Choice) {
+    let mut b3 = *b;
+    b3.double();
+    b3.add_assign(b);
+
+    let p = Homogeneous::conditional_select(
+        &Homogeneous { x: *x, y: *y, z: F::one() },
+        &Homogeneous::zero(),
+        infinity,
+    );
+
+    // [0] P, [1] P, ..., [2^(WINDOW - 1)] P
+    let mut table = vec![Homogeneous::zero()];
+    for i in 0..(1 << (WINDOW - 1)) {
+        let next = table[i].add(&p, a, &b3);
+        table.push(next);
+    }
+
+    let limbs = scalar.as_ref();
+    let mut digits = Vec::with_capacity(limbs.len() * 64 / WINDOW + 1);
+    let mut carry = 0i8;
+    for i in 0..(limbs.len() * 64 / WINDOW) {
+        let bits = (limbs[i * WINDOW / 64] >> (i * WINDOW % 64)) & ((1 << WINDOW) - 1);
+        let window = bits as i8 + carry;
+        carry = (window + (1 << (WINDOW - 1))) >> WINDOW;
+        digits.push(window - (carry << WINDOW));
+    }
+    digits.push(carry);
+    scalar.as_mut().zeroize();
+
+    let mut acc = Homogeneous::zero();
+    for &digit in digits.iter().rev() {
+        for _ in 0..WINDOW {
+            acc = acc.add(&acc, a, &b3);
+        }
+
+        let sign = (digit >> 7) as u8 & 1;
+        let abs = ((digit ^ -(sign as i8)) + sign as i8) as u8;
+
+        let mut term = table[0];
+        for (i, entry) in table.iter().enumerate().skip(1) {
+            term.conditional_assign(entry, abs.ct_eq(&(i as u8)));
+        }
+        term.y.conditional_negate(Choice::from(sign));
+
+        acc = acc.add(&term, a, &b3);
+    }
+    digits.zeroize();
+
+    let z_inv = acc.z.ct_inverse();
+    let infinity = z_inv.is_none();
+    let z_inv = z_inv.unwrap_or(F::zero());
+
+    acc.x.mul_assign(&z_inv);
+    acc.y.mul_assign(&z_inv);
+
+    (acc.x, acc.y, infinity)
+}
@@ -84,6 +84,109 @@ macro_rules! curve_impl {
             }
         }
 
+        impl ConditionallySelectable for $affine {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                $affine {
+                    x: $basefield::conditional_select(&a.x, &b.x, choice),
+                    y: $basefield::conditional_select(&a.y, &b.y, choice),
+                    infinity: u8::conditional_select(&(a.infinity as u8), &(b.infinity as u8), choice) != 0,
+                }
+            }
+        }
+
+        impl ConstantTimeEq for $affine {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                let self_is_zero = Choice::from(self.infinity as u8);
+                let other_is_zero = Choice::from(other.infinity as u8);
+
+                (self_is_zero & other_is_zero)
+                    | (!self_is_zero & !other_is_zero & self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y))
+            }
+        }
+
+        impl CtCurveAffine for $affine {
+            fn conditional_negate(&mut self, choice: Choice) {
+                // the point at infinity keeps its y coordinate, as in `negate`
+                let infinity = Choice::from(self.infinity as u8);
+                self.y.conditional_negate(choice & !infinity);
+            }
+
+            fn ct_mul<S: Into<<Self::Scalar as PrimeField>::Repr>>(&self, scalar: S) -> Self {
+                let (x, y, infinity) = crate::ct::mul(
+                    &self.x,
+                    &self.y,
+                    Choice::from(self.infinity as u8),
+                    &$basefield::zero(),
+                    &$affine::get_coeff_b(),
+                    scalar.into(),
+                );
+
+                $affine::conditional_select(&$affine { x, y, infinity: false }, &$affine::zero(), infinity)
+            }
+        }
+
+        impl ConditionallySelectable for $projective {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                $projective {
+                    x: $basefield::conditional_select(&a.x, &b.x, choice),
+                    y: $basefield::conditional_select(&a.y, &b.y, choice),
+                    z: $basefield::conditional_select(&a.z, &b.z, choice),
+                }
+            }
+        }
+
+        impl ConstantTimeEq for $projective {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                // The same comparison as `eq`, without returning early.
+                let mut z1 = self.z;
+                z1.square();
+                let mut z2 = other.z;
+                z2.square();
+
+                let mut x1 = self.x;
+                x1.mul_assign(&z2);
+                let mut x2 = other.x;
+                x2.mul_assign(&z1);
+
+                z1.mul_assign(&self.z);
+                z2.mul_assign(&other.z);
+
+                let mut y1 = self.y;
+                y1.mul_assign(&z2);
+                let mut y2 = other.y;
+                y2.mul_assign(&z1);
+
+                let self_is_zero = self.z.ct_eq(&$basefield::zero());
+                let other_is_zero = other.z.ct_eq(&$basefield::zero());
+
+                (self_is_zero & other_is_zero)
+                    | (!self_is_zero & !other_is_zero & x1.ct_eq(&x2) & y1.ct_eq(&y2))
+            }
+        }
+
+        impl CtCurveProjective for $projective {
+            fn conditional_negate(&mut self, choice: Choice) {
+                self.y.conditional_negate(choice);
+            }
+
+            fn ct_into_affine(&self) -> $affine {
+                let z_inv = self.z.ct_inverse();
+                let infinity = z_inv.is_none();
+                let z_inv = z_inv.unwrap_or($basefield::zero());
+
+                // (X / Z^2, Y / Z^3)
+                let mut z_inv2 = z_inv;
+                z_inv2.square();
+                let mut x = self.x;
+                x.mul_assign(&z_inv2);
+                z_inv2.mul_assign(&z_inv);
+                let mut y = self.y;
+                y.mul_assign(&z_inv2);
+
+                $affine::conditional_select(&$affine { x, y, infinity: false }, &$affine::zero(), infinity)
+            }
+        }
+
         impl $affine {
             fn mul_bits<S: AsRef<[u64]>>(&self, bits: BitIterator<S>) -> $projective {
                 let mut res = $projective::zero();
@@ -122,25 +225,92 @@ macro_rules! curve_impl {
                 })
             }
 
-            fn is_on_curve(&self) -> bool {
-                if self.is_zero() {
-                    true
+            /// Constructs a point from its affine coordinates, checking that it
+            /// is on the curve and in the prime order subgroup. The coordinates
+            /// (0, 0) denote the point at infinity.
+            pub fn from_xy_checked(x: $basefield, y: $basefield) -> Result<Self, GroupDecodingError> {
+                let p = Self::from_xy_unchecked(x, y);
+
+                if !p.is_on_curve() {
+                    Err(GroupDecodingError::NotOnCurve)
+                } else if !p.is_in_correct_subgroup_assuming_on_curve() {
+                    Err(GroupDecodingError::NotInSubgroup)
                 } else {
-                    // Check that the point is on the curve
-                    let mut y2 = self.y;
-                    y2.square();
+                    Ok(p)
+                }
+            }
 
-                    let mut x3b = self.x;
-                    x3b.square();
-                    x3b.mul_assign(&self.x);
-                    x3b.add_assign(&Self::get_coeff_b());
+            /// Constructs a point from its affine coordinates without checking
+            /// that it is on the curve or in the prime order subgroup. The
+            /// coordinates (0, 0) denote the point at infinity.
+            ///
+            /// If the point is invalid, this can break API invariants, so
+            /// caution is strongly encouraged.
+            pub fn from_xy_unchecked(x: $basefield, y: $basefield) -> Self {
+                if x.is_zero() && y.is_zero() {
+                    Self::zero()
+                } else {
+                    $affine {
+                        x,
+                        y,
+                        infinity: false
+                    }
+                }
+            }
 
-                    y2 == x3b
+            /// Constructs the point with the given x-coordinate, checking that
+            /// it is in the prime order subgroup. If and only if `greatest` is
+            /// set will the lexicographically largest y-coordinate be selected,
+            /// as with the sign flag of the compressed encoding.
+            pub fn from_x_and_sign(x: $basefield, greatest: bool) -> Result<Self, GroupDecodingError> {
+                let p = Self::get_point_from_x(x, greatest).ok_or(GroupDecodingError::NotOnCurve)?;
+
+                if p.is_in_correct_subgroup_assuming_on_curve() {
+                    Ok(p)
+                } else {
+                    Err(GroupDecodingError::NotInSubgroup)
+                }
+            }
+
+            /// Returns the x-coordinate, or zero for the point at infinity.
+            pub fn x(&self) -> $basefield {
+                if self.infinity {
+                    $basefield::zero()
+                } else {
+                    self.x
                 }
             }
 
-            fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
-                self.mul($scalarfield::char()).is_zero()
+            /// Returns the y-coordinate, or zero for the point at infinity.
+            pub fn y(&self) -> $basefield {
+                if self.infinity {
+                    $basefield::zero()
+                } else {
+                    self.y
+                }
+            }
+        }
+
+        #[cfg(test)]
+        impl crate::tests::curve::XyConstructors for $affine {
+            fn x(&self) -> $basefield {
+                $affine::x(self)
+            }
+
+            fn y(&self) -> $basefield {
+                $affine::y(self)
+            }
+
+            fn from_xy_checked(x: $basefield, y: $basefield) -> Result<Self, GroupDecodingError> {
+                $affine::from_xy_checked(x, y)
+            }
+
+            fn from_xy_unchecked(x: $basefield, y: $basefield) -> Self {
+                $affine::from_xy_unchecked(x, y)
+            }
+
+            fn from_x_and_sign(x: $basefield, greatest: bool) -> Result<Self, GroupDecodingError> {
+                $affine::from_x_and_sign(x, greatest)
             }
         }
 
@@ -194,6 +364,27 @@ macro_rules! curve_impl {
                 (*self).into()
             }
 
+            fn is_on_curve(&self) -> bool {
+                if self.is_zero() {
+                    true
+                } else {
+                    // Check that the point is on the curve
+                    let mut y2 = self.y;
+                    y2.square();
+
+                    let mut x3b = self.x;
+                    x3b.square();
+                    x3b.mul_assign(&self.x);
+                    x3b.add_assign(&Self::get_coeff_b());
+
+                    y2 == x3b
+                }
+            }
+
+            fn is_in_subgroup(&self) -> bool {
+                self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve()
+            }
+
         }
 
         impl Rand for $projective {
@@ -213,6 +404,28 @@ macro_rules! curve_impl {
             }
         }
 
+        impl $projective {
+            fn mul_bits<S: AsRef<[u64]>>(&self, bits: BitIterator<S>) -> $projective {
+                let mut res = $projective::zero();
+
+                let mut found_one = false;
+
+                for i in bits {
+                    if found_one {
+                        res.double();
+                    } else {
+                        found_one = i;
+                    }
+
+                    if i {
+                        res.add_assign(self);
+                    }
+                }
+
+                res
+            }
+        }
+
         impl CurveProjective for $projective {
             type Engine = Bls12;
             type Scalar = $scalarfield;
@@ -245,52 +458,56 @@ macro_rules! curve_impl {
 
             fn batch_normalization(v: &mut [Self])
             {
-                // Montgomery’s Trick and Fast Implementation of Masked AES
-                // Genelle, Prouff and Quisquater
-                // Section 3.2
-
-                // First pass: compute [a, ab, abc, ...]
-                let mut prod = Vec::with_capacity(v.len());
-                let mut tmp = $basefield::one();
-                for g in v.iter_mut()
-                          // Ignore normalized elements
-                          .filter(|g| !g.is_normalized())
-                {
-                    tmp.mul_assign(&g.z);
-                    prod.push(tmp);
-                }
+                // Chunks are normalized independently, on several threads with the
+                // `parallel` feature.
+                crate::parallel::for_each_chunk(v, 1024, |v| {
+                    // Montgomery’s Trick and Fast Implementation of Masked AES
+                    // Genelle, Prouff and Quisquater
+                    // Section 3.2
+
+                    // First pass: compute [a, ab, abc, ...]
+                    let mut prod = Vec::with_capacity(v.len());
+                    let mut tmp = $basefield::one();
+                    for g in v.iter_mut()
+                              // Ignore normalized elements
+                              .filter(|g| !g.is_normalized())
+                    {
+                        tmp.mul_assign(&g.z);
+                        prod.push(tmp);
+                    }
 
-                // Invert `tmp`.
-                tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-                // Second pass: iterate backwards to compute inverses
-                for (g, s) in v.iter_mut()
-                               // Backwards
-                               .rev()
-                               // Ignore normalized elements
-                               .filter(|g| !g.is_normalized())
-                               // Backwards, skip last element, fill in one for last term.
-                               .zip(prod.into_iter().rev().skip(1).chain(Some($basefield::one())))
-                {
-                    // tmp := tmp * g.z; g.z := tmp * s = 1/z
-                    let mut newtmp = tmp;
-                    newtmp.mul_assign(&g.z);
-                    g.z = tmp;
-                    g.z.mul_assign(&s);
-                    tmp = newtmp;
-                }
+                    // Invert `tmp`.
+                    tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
+
+                    // Second pass: iterate backwards to compute inverses
+                    for (g, s) in v.iter_mut()
+                                   // Backwards
+                                   .rev()
+                                   // Ignore normalized elements
+                                   .filter(|g| !g.is_normalized())
+                                   // Backwards, skip last element, fill in one for last term.
+                                   .zip(prod.into_iter().rev().skip(1).chain(Some($basefield::one())))
+                    {
+                        // tmp := tmp * g.z; g.z := tmp * s = 1/z
+                        let mut newtmp = tmp;
+                        newtmp.mul_assign(&g.z);
+                        g.z = tmp;
+                        g.z.mul_assign(&s);
+                        tmp = newtmp;
+                    }
 
-                // Perform affine transformations
-                for g in v.iter_mut()
-                          .filter(|g| !g.is_normalized())
-                {
-                    let mut z = g.z; // 1/z
-                    z.square(); // 1/z^2
-                    g.x.mul_assign(&z); // x/z^2
-                    z.mul_assign(&g.z); // 1/z^3
-                    g.y.mul_assign(&z); // y/z^3
-                    g.z = $basefield::one(); // z = 1
-                }
+                    // Perform affine transformations
+                    for g in v.iter_mut()
+                              .filter(|g| !g.is_normalized())
+                    {
+                        let mut z = g.z; // 1/z
+                        z.square(); // 1/z^2
+                        g.x.mul_assign(&z); // x/z^2
+                        z.mul_assign(&g.z); // 1/z^3
+                        g.y.mul_assign(&z); // y/z^3
+                        g.z = $basefield::one(); // z = 1
+                    }
+                });
             }
 
             fn double(&mut self) {
@@ -532,30 +749,17 @@ macro_rules! curve_impl {
             }
 
             fn mul_assign<S: Into<<Self::Scalar as PrimeField>::Repr>>(&mut self, other: S) {
-                let mut res = Self::zero();
-
-                let mut found_one = false;
-
-                for i in BitIterator::new(other.into())
-                {
-                    if found_one {
-                        res.double();
-                    } else {
-                        found_one = i;
-                    }
-
-                    if i {
-                        res.add_assign(self);
-                    }
-                }
-
-                *self = res;
+                *self = self.mul_bits(BitIterator::new(other.into()));
             }
 
             fn into_affine(&self) -> $affine {
                 (*self).into()
             }
 
+            fn clear_cofactor(&self) -> Self {
+                self.mul_by_effective_cofactor()
+            }
+
             fn recommended_wnaf_for_scalar(scalar: <Self::Scalar as PrimeField>::Repr) -> usize {
                 Self::empirical_recommended_wnaf_for_scalar(scalar)
             }
@@ -565,6 +769,15 @@ macro_rules! curve_impl {
             }
         }
 
+        // Points are (de)serialized through their compressed encoding, which
+        // checks subgroup membership when decoding.
+        #[cfg(feature = "serde")]
+        serde_impl!(
+            $affine,
+            crate::serialization::serialize_point,
+            crate::serialization::deserialize_point
+        );
+
         // The affine point X, Y is represented in the jacobian
         // coordinates with Z = 1.
         impl From<$affine> for $projective {
@@ -621,10 +834,13 @@ macro_rules! curve_impl {
 }
 
 pub mod g1 {
-    use super::super::{Bls12, Fq, Fq12, FqRepr, Fr, FrRepr};
+    use super::super::fq::BETA;
+    use crate::glv::{glv_mul, GlvParameters};
+    use super::super::{Bls12, Fq, Fq12, FqRepr, Fr, FrRepr, BLS_X};
     use super::g2::G2Affine;
     use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
     use rand::{Rand, Rng};
+    use crate::ct::{Choice, ConditionallySelectable, ConstantTimeEq, CtCurveAffine, CtCurveProjective, CtField};
     use std::fmt;
     use crate::{RawEncodable, CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
 
@@ -918,13 +1134,56 @@ pub mod g1 {
         }
     }
 
+    // The GLV decomposition for sigma, with the lattice basis
+    // (1, 1 - x^2), (x^2, 1).
+    const GLV_PARAMETERS: GlvParameters = GlvParameters {
+        basis: &[
+            &[(false, 0x1), (true, 0xac45a4010001a40200000000ffffffff)],
+            &[(false, 0xac45a4010001a4020000000100000000), (false, 0x1)],
+        ],
+        g: &[
+            (false, &[0x2]),
+            (false, &[0x63f6e522f6cfee2e, 0x7c6becf1e01faadd, 0x1]),
+        ],
+    };
+
     impl G1Affine {
+        /// Multiplies by `k` with the GLV method. See `G1::mul_glv`.
+        pub fn mul_glv<S: Into<FrRepr>>(&self, k: S) -> G1 {
+            self.into_projective().mul_glv(k)
+        }
+
         fn scale_by_cofactor(&self) -> G1 {
             // G1 cofactor = (x - 1)^2 / 3  = 76329603384216526031706109802092473003
             let cofactor = BitIterator::new([0x8c00aaab0000aaab, 0x396c8c005555e156]);
             self.mul_bits(cofactor)
         }
 
+        /// Computes the endomorphism sigma(x, y) = (beta * x, y), which acts
+        /// on G1 as multiplication by -x^2.
+        fn sigma(&self) -> G1Affine {
+            let mut res = *self;
+            res.x.mul_assign(&BETA);
+            res
+        }
+
+        /// Checks that a point on E1 is in G1, using that it is so if and only
+        /// if sigma(P) = -[x^2]P. See section 6 of Scott,
+        /// https://eprint.iacr.org/2021/1130.pdf, and the proof in
+        /// https://eprint.iacr.org/2022/352.pdf.
+        fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+            if self.is_zero() {
+                return true;
+            }
+
+            let x_squared = (BLS_X as u128) * (BLS_X as u128);
+            let mut minus_x_squared_p =
+                self.mul_bits(BitIterator::new([x_squared as u64, (x_squared >> 64) as u64]));
+            minus_x_squared_p.negate();
+
+            self.sigma().into_projective() == minus_x_squared_p
+        }
+
         fn get_generator() -> Self {
             G1Affine {
                 x: super::super::fq::G1_GENERATOR_X,
@@ -943,6 +1202,21 @@ pub mod g1 {
     }
 
     impl G1 {
+        /// Computes the endomorphism sigma(x, y) = (beta * x, y).
+        fn sigma(&self) -> G1 {
+            let mut res = *self;
+            res.x.mul_assign(&BETA);
+            res
+        }
+
+        /// Multiplies by `k` with the GLV method, which is faster than
+        /// `mul_assign`. The result is only correct for points in G1, on which
+        /// sigma acts as multiplication by -x^2, so points that were not
+        /// checked to be in G1 must use `mul_assign`.
+        pub fn mul_glv<S: Into<FrRepr>>(&self, k: S) -> G1 {
+            glv_mul(self, k.into(), &GLV_PARAMETERS, G1::sigma)
+        }
+
         fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
             let num_bits = scalar.num_bits() as usize;
 
@@ -970,6 +1244,15 @@ pub mod g1 {
 
             ret
         }
+
+        /// Maps a point of E1 into G1 by multiplying it with the effective
+        /// cofactor h_eff = 1 - x (RFC 9380, section 8.8.1), which is much
+        /// smaller than the cofactor used by `scale_by_cofactor`.
+        fn mul_by_effective_cofactor(&self) -> G1 {
+            let mut res = *self;
+            res.mul_assign(FrRepr::from(BLS_X + 1));
+            res
+        }
     }
 
     #[derive(Clone, Debug)]
@@ -1312,18 +1595,140 @@ pub mod g1 {
         assert_eq!(tmp2, c.into_projective());
     }
 
+    #[test]
+    fn test_g1_clear_cofactor() {
+        use rand::{SeedableRng, XorShiftRng};
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let mut i = 0;
+        while i < 10 {
+            let x = Fq::rand(&mut rng);
+            if let Some(p) = G1Affine::get_point_from_x(x, rng.gen()) {
+                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+                assert!(p
+                    .into_projective()
+                    .clear_cofactor()
+                    .into_affine()
+                    .is_in_correct_subgroup_assuming_on_curve());
+
+                i += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_g1_subgroup_check() {
+        use rand::{SeedableRng, XorShiftRng};
+
+        // Reject the point (0, 2) of order 3
+        {
+            let p = G1Affine {
+                x: Fq::zero(),
+                y: Fq::from_repr(FqRepr::from(2)).unwrap(),
+                infinity: false,
+            };
+            assert!(p.is_on_curve());
+            assert!(p.mul(FrRepr::from(3)).is_zero());
+            assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+
+            assert!(matches!(
+                p.into_uncompressed().into_affine(),
+                Err(GroupDecodingError::NotInSubgroup)
+            ));
+            assert!(matches!(
+                p.into_compressed().into_affine(),
+                Err(GroupDecodingError::NotInSubgroup)
+            ));
+            assert!(matches!(
+                G1Affine::from_xy_checked(p.x(), p.y()),
+                Err(GroupDecodingError::NotInSubgroup)
+            ));
+            assert!(matches!(
+                G1Affine::from_x_and_sign(p.x(), false),
+                Err(GroupDecodingError::NotInSubgroup)
+            ));
+
+            #[cfg(feature = "serde")]
+            crate::tests::serialization::serde_rejects_point(p);
+        }
+
+        // The fast check agrees with multiplication by r
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let mut i = 0;
+        while i < 10 {
+            let x = Fq::rand(&mut rng);
+            if let Some(p) = G1Affine::get_point_from_x(x, rng.gen()) {
+                assert_eq!(
+                    p.is_in_correct_subgroup_assuming_on_curve(),
+                    p.mul(Fr::char()).is_zero()
+                );
+
+                let p = p.into_projective().clear_cofactor().into_affine();
+                assert!(p.is_in_correct_subgroup_assuming_on_curve());
+                assert!(p.mul(Fr::char()).is_zero());
+                assert_eq!(p.into_compressed().into_affine().unwrap(), p);
+
+                i += 1;
+            }
+        }
+
+        assert!(G1Affine::zero().is_in_correct_subgroup_assuming_on_curve());
+        assert!(G1Affine::one().is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    #[test]
+    fn test_glv_decomposition() {
+        use crate::glv::glv_decompose;
+        use rand::{SeedableRng, XorShiftRng};
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let mut minus_one = Fr::one();
+        minus_one.negate();
+
+        let mut scalars = vec![Fr::zero(), Fr::one(), minus_one];
+        scalars.extend((0..1000).map(|_| Fr::rand(&mut rng)));
+
+        for k in scalars {
+            for (_, k_i) in glv_decompose::<Fr>(k.into_repr(), &GLV_PARAMETERS) {
+                assert!(k_i.num_bits() <= 129);
+            }
+        }
+    }
+
+    #[test]
+    fn test_g1_mul_glv() {
+        crate::tests::curve::endomorphism_mul_tests::<G1, _>(|p, k| p.mul_glv(k));
+        crate::tests::curve::endomorphism_mul_tests::<G1, _>(|p, k| p.into_affine().mul_glv(k));
+    }
+
     #[test]
     fn g1_curve_tests() {
         crate::tests::curve::curve_tests::<G1>();
         crate::tests::curve::random_transformation_tests_with_cofactor::<G1>();
     }
+
+    #[test]
+    fn g1_ct_tests() {
+        crate::tests::curve::ct_tests::<G1>();
+    }
+
+    #[test]
+    fn test_g1_xy_constructors() {
+        crate::tests::curve::xy_constructor_tests::<G1>();
+    }
 }
 
 pub mod g2 {
-    use super::super::{Bls12, Fq, Fq12, Fq2, FqRepr, Fr, FrRepr};
+    use super::super::fq::{PSI_COEFF_X, PSI_COEFF_Y};
+    use crate::glv::{glv_mul, GlvParameters};
+    use super::super::{Bls12, Fq, Fq12, Fq2, FqRepr, Fr, FrRepr, BLS_X, BLS_X_IS_NEGATIVE};
     use super::g1::G1Affine;
     use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
     use rand::{Rand, Rng};
+    use crate::ct::{Choice, ConditionallySelectable, ConstantTimeEq, CtCurveAffine, CtCurveProjective, CtField};
     use std::fmt;
     use crate::{CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
 
@@ -1589,7 +1994,44 @@ pub mod g2 {
         }
     }
 
+    // The GLS decomposition for psi, which acts on G2 as multiplication by x,
+    // with the lattice basis (x, -1, 0, 0), (0, x, -1, 0), (0, 0, x, -1),
+    // (1, 0, -1, x).
+    const GLS_PARAMETERS: GlvParameters = GlvParameters {
+        basis: &[
+            &[(true, 0xd201000000010000), (true, 0x1), (false, 0x0), (false, 0x0)],
+            &[(false, 0x0), (true, 0xd201000000010000), (true, 0x1), (false, 0x0)],
+            &[(false, 0x0), (false, 0x0), (true, 0xd201000000010000), (true, 0x1)],
+            &[(false, 0x1), (false, 0x0), (true, 0x1), (true, 0xd201000000010000)],
+        ],
+        g: &[
+            (true, &[0x92078a5e8573b29c, 0x33cfcc0d3e76ec28, 0x381204ca56cd56b5, 0x1]),
+            (false, &[0x63f6e522f6cfee2e, 0x7c6becf1e01faadd, 0x1]),
+            (true, &[0xcfbe4f7bd0027db2, 0x1]),
+            (false, &[0x2]),
+        ],
+    };
+
     impl G2Affine {
+        /// Multiplies by `k` with the GLS method. See `G2::mul_gls`.
+        pub fn mul_gls<S: Into<FrRepr>>(&self, k: S) -> G2 {
+            self.into_projective().mul_gls(k)
+        }
+
+        /// Checks that a point on E2 is in G2, using that it is so if and only
+        /// if psi(P) = [x]P. See section 4 of Scott,
+        /// https://eprint.iacr.org/2021/1130.pdf, and the proof in
+        /// https://eprint.iacr.org/2022/352.pdf.
+        fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+            if self.is_zero() {
+                return true;
+            }
+
+            let p = self.into_projective();
+
+            p.psi() == p.mul_by_x()
+        }
+
         fn get_generator() -> Self {
             G2Affine {
                 x: Fq2 {
@@ -1633,6 +2075,14 @@ pub mod g2 {
     }
 
     impl G2 {
+        /// Multiplies by `k` with the GLS method, which is faster than
+        /// `mul_assign`. The result is only correct for points in G2, on which
+        /// psi acts as multiplication by x, so points of the twist that were
+        /// not checked to be in G2 must use `mul_assign`.
+        pub fn mul_gls<S: Into<FrRepr>>(&self, k: S) -> G2 {
+            glv_mul(self, k.into(), &GLS_PARAMETERS, G2::psi)
+        }
+
         fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
             let num_bits = scalar.num_bits() as usize;
 
@@ -1660,11 +2110,79 @@ pub mod g2 {
 
             ret
         }
+
+        /// Multiplies this element by the BLS parameter x.
+        fn mul_by_x(&self) -> G2 {
+            let mut res = G2::zero();
+            for i in BitIterator::new([BLS_X]) {
+                res.double();
+                if i {
+                    res.add_assign(self);
+                }
+            }
+            if BLS_X_IS_NEGATIVE {
+                res.negate();
+            }
+            res
+        }
+
+        /// Computes the endomorphism psi = untwist-Frobenius-twist, which acts
+        /// on G2 as multiplication by q.
+        pub(crate) fn psi(&self) -> G2 {
+            let mut res = *self;
+            res.x.frobenius_map(1);
+            res.y.frobenius_map(1);
+            res.z.frobenius_map(1);
+            res.x.mul_assign(&PSI_COEFF_X);
+            res.y.mul_assign(&PSI_COEFF_Y);
+            res
+        }
+
+        /// Maps a point of E2 into G2 by multiplying it with the effective
+        /// cofactor h_eff (RFC 9380, section 8.8.2). Uses the method of
+        /// Budroni and Pintore, https://eprint.iacr.org/2017/419.pdf,
+        /// h_eff * P = [x^2 - x - 1] P + [x - 1] psi(P) + psi^2(2 P).
+        fn mul_by_effective_cofactor(&self) -> G2 {
+            let t1 = self.mul_by_x();
+            let mut t2 = self.psi();
+            let mut t3 = *self;
+            t3.double();
+            t3 = t3.psi().psi();
+            t3.sub_assign(&t2);
+            t2.add_assign(&t1);
+            t2 = t2.mul_by_x();
+            t3.add_assign(&t2);
+            t3.sub_assign(&t1);
+            t3.sub_assign(self);
+            t3
+        }
     }
 
-    #[derive(Clone, Debug)]
+    #[derive(Clone, Debug, PartialEq, Eq)]
     pub struct G2Prepared {
-        pub(crate) coeffs: Vec<(Fq2, Fq2, Fq2)>,
+        pub(crate) p: G2Affine,
+        pub(crate) lines: G2PreparedLines,
+        pub(crate) infinity: bool,
+    }
+
+    /// The line coefficients of a `G2Prepared`.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub(crate) enum G2PreparedLines {
+        /// The coefficients of `y`, `x` and the constant term of each line,
+        /// as computed in projective coordinates.
+        Projective(Vec<(Fq2, Fq2, Fq2)>),
+        /// The lines of a `G2PreparedAffine`.
+        Affine(Vec<(Fq2, Fq2)>),
+    }
+
+    /// A prepared element of G2 whose lines are normalized to `y + a * x + b`
+    /// and stored as `(a, b)`, which takes two thirds of the memory of
+    /// `G2Prepared`. It can be paired directly or converted into a
+    /// `G2Prepared` for `Engine::miller_loop`.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct G2PreparedAffine {
+        pub(crate) p: G2Affine,
+        pub(crate) coeffs: Vec<(Fq2, Fq2)>,
         pub(crate) infinity: bool,
     }
 
@@ -1753,7 +2271,13 @@ pub mod g2 {
                 infinity: false,
             };
             assert!(!p.is_on_curve());
-            assert!(p.is_in_correct_subgroup_assuming_on_curve());
+            // The point has order r, but the endomorphism-based subgroup check
+            // is only meaningful on E2, so the decoder must reject it as
+            // being off the curve.
+            assert!(matches!(
+                p.into_uncompressed().into_affine(),
+                Err(GroupDecodingError::NotOnCurve)
+            ));
         }
 
         // Reject point on a twist (b = 2 * (u + 1))
@@ -2066,11 +2590,128 @@ pub mod g2 {
         );
     }
 
+    #[test]
+    fn test_g2_psi() {
+        use rand::{SeedableRng, XorShiftRng};
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        // psi acts on G2 as multiplication by x
+        for _ in 0..10 {
+            let p = G2::rand(&mut rng);
+            assert_eq!(p.psi(), p.mul_by_x());
+        }
+    }
+
+    #[test]
+    fn test_g2_subgroup_check() {
+        use rand::{SeedableRng, XorShiftRng};
+
+        // The fast check agrees with multiplication by r
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let mut i = 0;
+        while i < 10 {
+            let x = Fq2::rand(&mut rng);
+            if let Some(p) = G2Affine::get_point_from_x(x, rng.gen()) {
+                assert_eq!(
+                    p.is_in_correct_subgroup_assuming_on_curve(),
+                    p.mul(Fr::char()).is_zero()
+                );
+                assert!(matches!(
+                    p.into_compressed().into_affine(),
+                    Err(GroupDecodingError::NotInSubgroup)
+                ));
+
+                let p = p.into_projective().clear_cofactor().into_affine();
+                assert!(p.is_in_correct_subgroup_assuming_on_curve());
+                assert!(p.mul(Fr::char()).is_zero());
+                assert_eq!(p.into_compressed().into_affine().unwrap(), p);
+
+                i += 1;
+            }
+        }
+
+        assert!(G2Affine::zero().is_in_correct_subgroup_assuming_on_curve());
+        assert!(G2Affine::one().is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    #[test]
+    fn test_g2_clear_cofactor() {
+        use rand::{SeedableRng, XorShiftRng};
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        // h_eff from RFC 9380, section 8.8.2
+        let h_eff = [
+            0xe8020005aaa95551,
+            0x59894c0adebbf6b4,
+            0xe954cbc06689f6a3,
+            0x2ec0ec69d7477c1a,
+            0x6d82bf015d1212b0,
+            0x329c2f178731db95,
+            0x9986ff031508ffe1,
+            0x88e2a8e9145ad768,
+            0x584c6a0ea91b3528,
+            0xbc69f08f2ee75b3,
+        ];
+
+        let mut i = 0;
+        while i < 10 {
+            let x = Fq2::rand(&mut rng);
+            if let Some(p) = G2Affine::get_point_from_x(x, rng.gen()) {
+                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+
+                let res = p.into_projective().clear_cofactor();
+                assert_eq!(res, p.mul_bits(BitIterator::new(h_eff)));
+                assert!(res.into_affine().is_in_correct_subgroup_assuming_on_curve());
+
+                i += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_gls_decomposition() {
+        use crate::glv::glv_decompose;
+        use rand::{SeedableRng, XorShiftRng};
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let mut minus_one = Fr::one();
+        minus_one.negate();
+
+        let mut scalars = vec![Fr::zero(), Fr::one(), minus_one];
+        scalars.extend((0..1000).map(|_| Fr::rand(&mut rng)));
+
+        for k in scalars {
+            for (_, k_i) in glv_decompose::<Fr>(k.into_repr(), &GLS_PARAMETERS) {
+                assert!(k_i.num_bits() <= 66);
+            }
+        }
+    }
+
+    #[test]
+    fn test_g2_mul_gls() {
+        crate::tests::curve::endomorphism_mul_tests::<G2, _>(|p, k| p.mul_gls(k));
+        crate::tests::curve::endomorphism_mul_tests::<G2, _>(|p, k| p.into_affine().mul_gls(k));
+    }
+
     #[test]
     fn g2_curve_tests() {
         crate::tests::curve::curve_tests::<G2>();
         crate::tests::curve::random_transformation_tests_with_cofactor::<G2>();
     }
+
+    #[test]
+    fn g2_ct_tests() {
+        crate::tests::curve::ct_tests::<G2>();
+    }
+
+    #[test]
+    fn test_g2_xy_constructors() {
+        crate::tests::curve::xy_constructor_tests::<G2>();
+    }
 }
 
 pub use self::g1::*;
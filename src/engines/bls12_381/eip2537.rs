@@ -0,0 +1,903 @@
+//! The BLS12-381 precompiles of Ethereum, as specified by EIP-2537.
+//!
+//! A field element is encoded as 64 bytes: 16 zero bytes followed by the
+//! element as a 48-byte big-endian integer, which must be less than the
+//! modulus. An element of Fq2 is its `c0` coefficient followed by `c1`, and
+//! a point is its x and y coordinates, with `(0, 0)` standing for the point
+//! at infinity. A scalar is a 32-byte big-endian integer, which need not be
+//! less than the group order.
+//!
+//! Points given to the additions only need to be on the curve, while those
+//! given to the multi-scalar multiplications and the pairing check must also
+//! be in the prime order subgroup.
+
+use super::hash_to_curve::{map_to_curve_g1, map_to_curve_g2};
+use super::{Bls12, Fq, Fq2, FqRepr, Fr, FrRepr, G1Affine, G2Affine};
+use crate::{multiexp, CurveAffine, CurveProjective, Engine, GroupDecodingError};
+
+use ff::{PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
+use std::error::Error;
+use std::fmt;
+
+/// The length of an encoded element of Fq.
+pub const FP_LEN: usize = 64;
+
+/// The length of an encoded element of Fq2.
+pub const FP2_LEN: usize = 2 * FP_LEN;
+
+/// The length of an encoded point of G1.
+pub const G1_LEN: usize = 2 * FP_LEN;
+
+/// The length of an encoded point of G2.
+pub const G2_LEN: usize = 2 * FP2_LEN;
+
+/// The length of an encoded scalar.
+pub const SCALAR_LEN: usize = 32;
+
+/// The number of zero bytes before a field element.
+const PADDING_LEN: usize = FP_LEN - 48;
+
+/// An error in the input of a precompile, on which the call fails.
+#[derive(Debug)]
+pub enum PrecompileError {
+    /// The input does not have the length required by the precompile.
+    InvalidInputLength(usize),
+    /// An input field element is not canonically encoded.
+    InvalidFieldElement(PrimeFieldDecodingError),
+    /// A point is not canonically encoded, not on the curve or, where it is
+    /// required, not in the prime order subgroup.
+    InvalidPoint(GroupDecodingError),
+}
+
+impl From<GroupDecodingError> for PrecompileError {
+    fn from(e: GroupDecodingError) -> PrecompileError {
+        PrecompileError::InvalidPoint(e)
+    }
+}
+
+impl From<PrimeFieldDecodingError> for PrecompileError {
+    fn from(e: PrimeFieldDecodingError) -> PrecompileError {
+        PrecompileError::InvalidFieldElement(e)
+    }
+}
+
+impl Error for PrecompileError {
+    fn description(&self) -> &str {
+        match *self {
+            PrecompileError::InvalidInputLength(_) => "invalid input length",
+            PrecompileError::InvalidFieldElement(_) => "invalid field element",
+            PrecompileError::InvalidPoint(_) => "invalid point",
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        match *self {
+            PrecompileError::InvalidInputLength(_) => None,
+            PrecompileError::InvalidFieldElement(ref e) => Some(e),
+            PrecompileError::InvalidPoint(ref e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for PrecompileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            PrecompileError::InvalidInputLength(len) => write!(f, "invalid input length {}", len),
+            PrecompileError::InvalidFieldElement(ref e) => write!(f, "invalid field element: {}", e),
+            PrecompileError::InvalidPoint(ref e) => write!(f, "invalid point: {}", e),
+        }
+    }
+}
+
+/// Checks that `input` is `len` bytes long.
+fn check_len(input: &[u8], len: usize) -> Result<(), PrecompileError> {
+    if input.len() != len {
+        return Err(PrecompileError::InvalidInputLength(input.len()));
+    }
+
+    Ok(())
+}
+
+/// Checks that `input` is a nonzero number of `len`-byte items.
+fn check_items_len(input: &[u8], len: usize) -> Result<(), PrecompileError> {
+    if input.is_empty() || !input.len().is_multiple_of(len) {
+        return Err(PrecompileError::InvalidInputLength(input.len()));
+    }
+
+    Ok(())
+}
+
+/// Decodes an element of Fq from `FP_LEN` bytes.
+pub fn decode_fp(bytes: &[u8]) -> Result<Fq, PrimeFieldDecodingError> {
+    if bytes[..PADDING_LEN].iter().any(|&b| b != 0) {
+        return Err(PrimeFieldDecodingError::NotInField(
+            "the padding bytes are not zero".to_string(),
+        ));
+    }
+
+    let mut repr = FqRepr::default();
+    repr.read_be(&bytes[PADDING_LEN..FP_LEN]).unwrap();
+
+    Fq::from_repr(repr)
+}
+
+/// Decodes an element of Fq2 from `FP2_LEN` bytes.
+pub fn decode_fp2(bytes: &[u8]) -> Result<Fq2, PrimeFieldDecodingError> {
+    Ok(Fq2 {
+        c0: decode_fp(&bytes[..FP_LEN])?,
+        c1: decode_fp(&bytes[FP_LEN..FP2_LEN])?,
+    })
+}
+
+fn encode_fp(f: &Fq, out: &mut [u8]) {
+    f.into_repr().write_be(&mut out[PADDING_LEN..FP_LEN]).unwrap();
+}
+
+fn encode_fp2(f: &Fq2, out: &mut [u8]) {
+    encode_fp(&f.c0, &mut out[..FP_LEN]);
+    encode_fp(&f.c1, &mut out[FP_LEN..]);
+}
+
+/// Decodes a point of E1 without checking that it is in G1.
+fn decode_g1_on_curve(bytes: &[u8]) -> Result<G1Affine, GroupDecodingError> {
+    let x = decode_fp(&bytes[..FP_LEN])
+        .map_err(|e| GroupDecodingError::CoordinateDecodingError("x coordinate", e))?;
+    let y = decode_fp(&bytes[FP_LEN..G1_LEN])
+        .map_err(|e| GroupDecodingError::CoordinateDecodingError("y coordinate", e))?;
+
+    let p = G1Affine::from_xy_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(GroupDecodingError::NotOnCurve);
+    }
+
+    Ok(p)
+}
+
+/// Decodes a point of E2 without checking that it is in G2.
+fn decode_g2_on_curve(bytes: &[u8]) -> Result<G2Affine, GroupDecodingError> {
+    let x = decode_fp2(&bytes[..FP2_LEN])
+        .map_err(|e| GroupDecodingError::CoordinateDecodingError("x coordinate", e))?;
+    let y = decode_fp2(&bytes[FP2_LEN..G2_LEN])
+        .map_err(|e| GroupDecodingError::CoordinateDecodingError("y coordinate", e))?;
+
+    let p = G2Affine::from_xy_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(GroupDecodingError::NotOnCurve);
+    }
+
+    Ok(p)
+}
+
+/// Decodes a point of G1 from `G1_LEN` bytes.
+pub fn decode_g1(bytes: &[u8]) -> Result<G1Affine, GroupDecodingError> {
+    let p = decode_g1_on_curve(bytes)?;
+    if !p.is_in_subgroup() {
+        return Err(GroupDecodingError::NotInSubgroup);
+    }
+
+    Ok(p)
+}
+
+/// Decodes a point of G2 from `G2_LEN` bytes.
+pub fn decode_g2(bytes: &[u8]) -> Result<G2Affine, GroupDecodingError> {
+    let p = decode_g2_on_curve(bytes)?;
+    if !p.is_in_subgroup() {
+        return Err(GroupDecodingError::NotInSubgroup);
+    }
+
+    Ok(p)
+}
+
+/// Encodes a point of G1 as `G1_LEN` bytes.
+pub fn encode_g1(p: &G1Affine) -> [u8; G1_LEN] {
+    let mut res = [0u8; G1_LEN];
+    encode_fp(&p.x(), &mut res[..FP_LEN]);
+    encode_fp(&p.y(), &mut res[FP_LEN..]);
+
+    res
+}
+
+/// Encodes a point of G2 as `G2_LEN` bytes.
+pub fn encode_g2(p: &G2Affine) -> [u8; G2_LEN] {
+    let mut res = [0u8; G2_LEN];
+    encode_fp2(&p.x(), &mut res[..FP2_LEN]);
+    encode_fp2(&p.y(), &mut res[FP2_LEN..]);
+
+    res
+}
+
+/// Decodes a scalar, reduced modulo the group order.
+fn decode_scalar(bytes: &[u8]) -> FrRepr {
+    let mut scalar = FrRepr::default();
+    scalar.read_be(bytes).unwrap();
+
+    let r = Fr::char();
+    while scalar >= r {
+        scalar.sub_noborrow(&r);
+    }
+
+    scalar
+}
+
+/// Decodes pairs of a point and a scalar, for a multi-scalar multiplication.
+fn decode_msm_input<G, D>(
+    input: &[u8],
+    point_len: usize,
+    decode: D,
+) -> Result<(Vec<G>, Vec<FrRepr>), PrecompileError>
+where
+    D: Fn(&[u8]) -> Result<G, GroupDecodingError>,
+{
+    check_items_len(input, point_len + SCALAR_LEN)?;
+
+    let mut bases = vec![];
+    let mut scalars = vec![];
+    for item in input.chunks(point_len + SCALAR_LEN) {
+        bases.push(decode(&item[..point_len])?);
+        scalars.push(decode_scalar(&item[point_len..]));
+    }
+
+    Ok((bases, scalars))
+}
+
+/// The G1ADD precompile: adds two points of E1.
+pub fn g1_add(input: &[u8]) -> Result<[u8; G1_LEN], PrecompileError> {
+    check_len(input, 2 * G1_LEN)?;
+
+    let mut p = decode_g1_on_curve(&input[..G1_LEN])?.into_projective();
+    p.add_assign_mixed(&decode_g1_on_curve(&input[G1_LEN..])?);
+
+    Ok(encode_g1(&p.into_affine()))
+}
+
+/// The G2ADD precompile: adds two points of E2.
+pub fn g2_add(input: &[u8]) -> Result<[u8; G2_LEN], PrecompileError> {
+    check_len(input, 2 * G2_LEN)?;
+
+    let mut p = decode_g2_on_curve(&input[..G2_LEN])?.into_projective();
+    p.add_assign_mixed(&decode_g2_on_curve(&input[G2_LEN..])?);
+
+    Ok(encode_g2(&p.into_affine()))
+}
+
+/// The G1MSM precompile: computes the sum of the products of points of G1
+/// by scalars, each point followed by its scalar in the input.
+pub fn g1_msm(input: &[u8]) -> Result<[u8; G1_LEN], PrecompileError> {
+    let (bases, scalars) = decode_msm_input(input, G1_LEN, decode_g1)?;
+
+    Ok(encode_g1(&multiexp(&bases, &scalars).into_affine()))
+}
+
+/// The G2MSM precompile: computes the sum of the products of points of G2
+/// by scalars, each point followed by its scalar in the input.
+pub fn g2_msm(input: &[u8]) -> Result<[u8; G2_LEN], PrecompileError> {
+    let (bases, scalars) = decode_msm_input(input, G2_LEN, decode_g2)?;
+
+    Ok(encode_g2(&multiexp(&bases, &scalars).into_affine()))
+}
+
+/// The PAIRING_CHECK precompile: checks that the product of the pairings of
+/// the given pairs of points of G1 and G2 is one. The output is a 32-byte
+/// big-endian one if it is, and zero otherwise.
+pub fn pairing_check(input: &[u8]) -> Result<[u8; 32], PrecompileError> {
+    check_items_len(input, G1_LEN + G2_LEN)?;
+
+    let mut prepared = vec![];
+    for pair in input.chunks(G1_LEN + G2_LEN) {
+        let p = decode_g1(&pair[..G1_LEN])?;
+        let q = decode_g2(&pair[G1_LEN..])?;
+        prepared.push((p.prepare(), q.prepare()));
+    }
+
+    let terms: Vec<_> = prepared.iter().map(|(p, q)| (p, q)).collect();
+
+    let mut res = [0u8; 32];
+    res[31] = Bls12::pairing_product_is_one(&terms) as u8;
+
+    Ok(res)
+}
+
+/// The MAP_FP_TO_G1 precompile: maps an element of Fq to G1 as in the
+/// `encode_to_curve` of the BLS12381G1_XMD:SHA-256_SSWU_NU_ suite.
+pub fn map_fp_to_g1(input: &[u8]) -> Result<[u8; G1_LEN], PrecompileError> {
+    check_len(input, FP_LEN)?;
+
+    let u = decode_fp(input)?;
+
+    Ok(encode_g1(&map_to_curve_g1(&u).clear_cofactor().into_affine()))
+}
+
+/// The MAP_FP2_TO_G2 precompile: maps an element of Fq2 to G2 as in the
+/// `encode_to_curve` of the BLS12381G2_XMD:SHA-256_SSWU_NU_ suite.
+pub fn map_fp2_to_g2(input: &[u8]) -> Result<[u8; G2_LEN], PrecompileError> {
+    check_len(input, FP2_LEN)?;
+
+    let u = decode_fp2(input)?;
+
+    Ok(encode_g2(&map_to_curve_g2(&u).clear_cofactor().into_affine()))
+}
+
+#[cfg(test)]
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+fn fp_hex(s: &str) -> String {
+    format!("{}{}", "00".repeat(PADDING_LEN), s)
+}
+
+/// Encodes field elements given as 48-byte big-endian integers.
+#[cfg(test)]
+fn fps(s: &[&str]) -> Vec<u8> {
+    from_hex(&s.iter().map(|s| fp_hex(s)).collect::<String>())
+}
+
+#[cfg(test)]
+fn scalar(s: &str) -> Vec<u8> {
+    from_hex(&format!("{:0>64}", s))
+}
+
+// The generators of G1 and G2, and the points P1 and P2 used by the vectors
+// of EIP-2537
+#[cfg(test)]
+const G1_HEX: [&str; 2] = [
+    "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+    "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1",
+];
+
+#[cfg(test)]
+const G2_HEX: [&str; 4] = [
+    "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+    "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e",
+    "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+    "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be",
+];
+
+#[cfg(test)]
+const P1_HEX: [&str; 2] = [
+    "112b98340eee2777cc3c14163dea3ec97977ac3dc5c70da32e6e87578f44912e902ccef9efe28d4a78b8999dfbca9426",
+    "186b28d92356c4dfec4b5201ad099dbdede3781f8998ddf929b4cd7756192185ca7b8f4ef7088f813270ac3d48868a21",
+];
+
+#[cfg(test)]
+const P2_HEX: [&str; 4] = [
+    "103121a2ceaae586d240843a398967325f8eb5a93e8fea99b62b9f88d8556c80dd726a4b30e84a36eeabaf3592937f27",
+    "086b990f3da2aeac0a36143b7d7c824428215140db1bb859338764cb58458f081d92664f9053b50b3fbd2e4723121b68",
+    "0f9e7ba9a86a8f7624aa2b42dcc8772e1af4ae115685e60abc2c9b90242167acef3d0be4050bf935eed7c3b6fc7ba77e",
+    "0d22c3652d0dc6f0fc9316e14268477c2049ef772e852108d269d9c38dba1d4802e8dae479818184c08f9a569d878451",
+];
+
+// The scalar of the `random` vectors of EIP-2537
+#[cfg(test)]
+const RANDOM_SCALAR: &str = "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3";
+
+/// Returns a point of E1 outside of G1.
+#[cfg(test)]
+fn g1_outside_subgroup() -> G1Affine {
+    use super::fq::B_COEFF;
+    use ff::{Field, SqrtField};
+
+    let mut x = Fq::one();
+    loop {
+        let mut rhs = x;
+        rhs.square();
+        rhs.mul_assign(&x);
+        rhs.add_assign(&B_COEFF);
+
+        if let Some(y) = rhs.sqrt() {
+            return G1Affine::from_xy_unchecked(x, y);
+        }
+        x.add_assign(&Fq::one());
+    }
+}
+
+/// Returns a point of E2 outside of G2.
+#[cfg(test)]
+fn g2_outside_subgroup() -> G2Affine {
+    use super::fq::B_COEFF;
+    use ff::{Field, SqrtField};
+
+    let mut x = Fq2::one();
+    loop {
+        let mut rhs = x;
+        rhs.square();
+        rhs.mul_assign(&x);
+        rhs.add_assign(&Fq2 {
+            c0: B_COEFF,
+            c1: B_COEFF,
+        });
+
+        if let Some(y) = rhs.sqrt() {
+            return G2Affine::from_xy_unchecked(x, y);
+        }
+        x.c0.add_assign(&Fq::one());
+    }
+}
+
+#[test]
+fn test_encoding() {
+    let g1 = fps(&G1_HEX);
+    let g2 = fps(&G2_HEX);
+
+    assert_eq!(&encode_g1(&G1Affine::one())[..], &g1[..]);
+    assert_eq!(&encode_g2(&G2Affine::one())[..], &g2[..]);
+    assert_eq!(decode_g1(&g1).unwrap(), G1Affine::one());
+    assert_eq!(decode_g2(&g2).unwrap(), G2Affine::one());
+
+    assert_eq!(&encode_g1(&G1Affine::zero())[..], &[0u8; G1_LEN][..]);
+    assert_eq!(&encode_g2(&G2Affine::zero())[..], &[0u8; G2_LEN][..]);
+    assert!(decode_g1(&[0u8; G1_LEN]).unwrap().is_zero());
+    assert!(decode_g2(&[0u8; G2_LEN]).unwrap().is_zero());
+
+    // Nonzero padding
+    let mut bytes = g1.clone();
+    bytes[FP_LEN] = 1;
+    match decode_g1(&bytes) {
+        Err(GroupDecodingError::CoordinateDecodingError("y coordinate", _)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    // x + p
+    let p = "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+    let mut x = FqRepr::default();
+    x.read_be(&from_hex(p)[..]).unwrap();
+    x.add_nocarry(&G1Affine::one().x().into_repr());
+    let mut bytes = g1.clone();
+    x.write_be(&mut bytes[PADDING_LEN..FP_LEN]).unwrap();
+    match decode_g1(&bytes) {
+        Err(GroupDecodingError::CoordinateDecodingError("x coordinate", _)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    // Swapped coefficients
+    let swapped = fps(&[G2_HEX[1], G2_HEX[0], G2_HEX[3], G2_HEX[2]]);
+    match decode_g2(&swapped) {
+        Err(GroupDecodingError::NotOnCurve) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn test_add() {
+    let g1 = fps(&G1_HEX);
+    let p1 = fps(&P1_HEX);
+    let neg_g1 = fps(&[
+        G1_HEX[0],
+        "114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca",
+    ]);
+    let neg_p1 = fps(&[
+        P1_HEX[0],
+        "0195e911162921ba5ed055b496420f197693d36569ec34c63d7c0529a097d49e543070afba4b707e878e53c2b779208a",
+    ]);
+    let inf1 = vec![0u8; G1_LEN];
+
+    // The vectors of EIP-2537
+    let vectors = [
+        (
+            "bls_g1add_g1+p1",
+            [&g1[..], &p1[..]].concat(),
+            fps(&[
+                "0a40300ce2dec9888b60690e9a41d3004fda4886854573974fab73b046d3147ba5b7a5bde85279ffede1b45b3918d82d",
+                "06d3d887e9f53b9ec4eb6cedf5607226754b07c01ace7834f57f3e7315faefb739e59018e22c492006190fba4a870025",
+            ]),
+        ),
+        ("bls_g1add_(g1+0=g1)", [&g1[..], &inf1[..]].concat(), g1.clone()),
+        ("bls_g1add_(p1+0=p1)", [&p1[..], &inf1[..]].concat(), p1.clone()),
+        ("bls_g1add_(g1-g1=0)", [&g1[..], &neg_g1[..]].concat(), inf1.clone()),
+        ("bls_g1add_(p1-p1=0)", [&p1[..], &neg_p1[..]].concat(), inf1.clone()),
+        (
+            "bls_g1add_(g+g=2*g)",
+            [&g1[..], &g1[..]].concat(),
+            fps(&[
+                "0572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e",
+                "166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28",
+            ]),
+        ),
+        (
+            "bls_g1add_(p1+p1=2*p1)",
+            [&p1[..], &p1[..]].concat(),
+            fps(&[
+                "15222cddbabdd764c4bee0b3720322a65ff4712c86fc4b1588d0c209210a0884fa9468e855d261c483091b2bf7de6a63",
+                "09f9edb99bc3b75d7489735c98b16ab78b9386c5f7a1f76c7e96ac6eb5bbde30dbca31a74ec6e0f0b12229eecea33c39",
+            ]),
+        ),
+    ];
+
+    for (name, input, output) in vectors.iter() {
+        assert_eq!(&g1_add(input).unwrap()[..], &output[..], "{}", name);
+    }
+
+    let g2 = fps(&G2_HEX);
+    let p2 = fps(&P2_HEX);
+    let neg_g2 = fps(&[
+        G2_HEX[0],
+        G2_HEX[1],
+        "0d1b3cc2c7027888be51d9ef691d77bcb679afda66c73f17f9ee3837a55024f78c71363275a75d75d86bab79f74782aa",
+        "13fa4d4a0ad8b1ce186ed5061789213d993923066dddaf1040bc3ff59f825c78df74f2d75467e25e0f55f8a00fa030ed",
+    ]);
+    let neg_p2 = fps(&[
+        P2_HEX[0],
+        P2_HEX[1],
+        "0a6296409115572426717c73668335a949829d739cff2cb4ab043710d28f8e772f6ef41aac4806c9cb273c490384032d",
+        "0cde4e850c721fa94e8890d500e3655b442d5c0dc4fff1b694c6f8dd68f6d8dc1bc3251a37d27e7af96f65a96278265a",
+    ]);
+    let inf2 = vec![0u8; G2_LEN];
+
+    let vectors = [
+        (
+            "bls_g2add_g2+p2",
+            [&g2[..], &p2[..]].concat(),
+            fps(&[
+                "0b54a8a7b08bd6827ed9a797de216b8c9057b3a9ca93e2f88e7f04f19accc42da90d883632b9ca4dc38d013f71ede4db",
+                "077eba4eecf0bd764dce8ed5f45040dd8f3b3427cb35230509482c14651713282946306247866dfe39a8e33016fcbe52",
+                "14e60a76a29ef85cbd69f251b9f29147b67cfe3ed2823d3f9776b3a0efd2731941d47436dc6d2b58d9e65f8438bad073",
+                "1586c3c910d95754fef7a732df78e279c3d37431c6a2b77e67a00c7c130a8fcd4d19f159cbeb997a178108fffffcbd20",
+            ]),
+        ),
+        ("bls_g2add_(g2+0=g2)", [&g2[..], &inf2[..]].concat(), g2.clone()),
+        ("bls_g2add_(p2+0=p2)", [&p2[..], &inf2[..]].concat(), p2.clone()),
+        ("bls_g2add_(g2-g2=0)", [&g2[..], &neg_g2[..]].concat(), inf2.clone()),
+        ("bls_g2add_(p2-p2=0)", [&p2[..], &neg_p2[..]].concat(), inf2.clone()),
+        (
+            "bls_g2add_(g2+g2=2*g2)",
+            [&g2[..], &g2[..]].concat(),
+            fps(&[
+                "1638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053",
+                "0a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577",
+                "0468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899",
+                "0f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf3",
+            ]),
+        ),
+        (
+            "bls_g2add_(p2+p2=2*p2)",
+            [&p2[..], &p2[..]].concat(),
+            fps(&[
+                "0b76fcbb604082a4f2d19858a7befd6053fa181c5119a612dfec83832537f644e02454f2b70d40985ebb08042d1620d4",
+                "19a4a02c0ae51365d964c73be7babb719db1c69e0ddbf9a8a335b5bed3b0a4b070d2d5df01d2da4a3f1e56aae2ec106d",
+                "0d18322f821ac72d3ca92f92b000483cf5b7d9e5d06873a44071c4e7e81efd904f210208fe0b9b4824f01c65bc7e6208",
+                "04e563d53609a2d1e216aaaee5fbc14ef460160db8d1fdc5e1bd4e8b54cd2f39abf6f925969fa405efb9e700b01c7085",
+            ]),
+        ),
+    ];
+
+    for (name, input, output) in vectors.iter() {
+        assert_eq!(&g2_add(input).unwrap()[..], &output[..], "{}", name);
+    }
+
+    // Points outside of the subgroups can be added
+    let p = g1_outside_subgroup();
+    assert!(!p.is_in_subgroup());
+    let mut sum = p.into_projective();
+    sum.add_assign_mixed(&G1Affine::one());
+    assert_eq!(
+        &g1_add(&[&encode_g1(&p)[..], &g1[..]].concat()).unwrap()[..],
+        &encode_g1(&sum.into_affine())[..]
+    );
+
+    let q = g2_outside_subgroup();
+    assert!(!q.is_in_subgroup());
+    let mut sum = q.into_projective();
+    sum.add_assign_mixed(&G2Affine::one());
+    assert_eq!(
+        &g2_add(&[&encode_g2(&q)[..], &g2[..]].concat()).unwrap()[..],
+        &encode_g2(&sum.into_affine())[..]
+    );
+
+    match g1_add(&g1) {
+        Err(PrecompileError::InvalidInputLength(128)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    let mut bytes = [&g1[..], &g1[..]].concat();
+    bytes[G1_LEN - 1] ^= 1;
+    match g1_add(&bytes) {
+        Err(PrecompileError::InvalidPoint(GroupDecodingError::NotOnCurve)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn test_msm() {
+    use super::{G1, G2};
+    use rand::{Rand, SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    let g1 = fps(&G1_HEX);
+    let p1 = fps(&P1_HEX);
+    let inf1 = vec![0u8; G1_LEN];
+    let (zero, one, two) = (scalar("0"), scalar("1"), scalar("2"));
+    let random = scalar(RANDOM_SCALAR);
+
+    // The vectors of EIP-2537, and the sum of the two random ones
+    let vectors = [
+        (
+            "bls_g1mul_(g1+g1=2*g1)",
+            [&g1[..], &two[..]].concat(),
+            fps(&[
+                "0572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e",
+                "166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28",
+            ]),
+        ),
+        (
+            "bls_g1mul_(p1+p1=2*p1)",
+            [&p1[..], &two[..]].concat(),
+            fps(&[
+                "15222cddbabdd764c4bee0b3720322a65ff4712c86fc4b1588d0c209210a0884fa9468e855d261c483091b2bf7de6a63",
+                "09f9edb99bc3b75d7489735c98b16ab78b9386c5f7a1f76c7e96ac6eb5bbde30dbca31a74ec6e0f0b12229eecea33c39",
+            ]),
+        ),
+        ("bls_g1mul_(1*g1=g1)", [&g1[..], &one[..]].concat(), g1.clone()),
+        ("bls_g1mul_(1*p1=p1)", [&p1[..], &one[..]].concat(), p1.clone()),
+        ("bls_g1mul_(0*g1=inf)", [&g1[..], &zero[..]].concat(), inf1.clone()),
+        ("bls_g1mul_(x*inf=inf)", [&inf1[..], &random[..]].concat(), inf1.clone()),
+        (
+            "bls_g1mul_random*g1",
+            [&g1[..], &random[..]].concat(),
+            fps(&[
+                "0491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a",
+                "17cd7061575d3e8034fcea62adaa1a3bc38dca4b50e4c5c01d04dd78037c9cee914e17944ea99e7ad84278e5d49f36c4",
+            ]),
+        ),
+        (
+            "bls_g1mul_random*p1",
+            [&p1[..], &random[..]].concat(),
+            fps(&[
+                "06ee9c9331228753bcb148d0ca8623447701bb0aa6eafb0340aa7f81543923474e00f2a225de65c62dd1d8303270220c",
+                "18dd7be47eb4e80985d7a0d2cc96c8b004250b36a5c3ec0217705d453d3ecc6d0d3d1588722da51b40728baba1e93804",
+            ]),
+        ),
+        (
+            "random*g1+random*p1",
+            [&g1[..], &random[..], &p1[..], &random[..]].concat(),
+            fps(&[
+                "01e31adf9b72abb736fc4b47629acc1f04060232bd952f10bd467397c5b8b10879e0b97ca7b0fc0c18ee96377144aae0",
+                "1868071a3eb67c2ad372d9b89c7a795e40a4429a82b9e3431c22b46fc9551cd85cedf8c94c54895aefb45e1ea080e383",
+            ]),
+        ),
+    ];
+
+    for (name, input, output) in vectors.iter() {
+        assert_eq!(&g1_msm(input).unwrap()[..], &output[..], "{}", name);
+    }
+
+    // r + 2
+    let mut r_plus_2 = Fr::char();
+    r_plus_2.add_nocarry(&FrRepr::from(2));
+    let mut scalar = [0u8; SCALAR_LEN];
+    r_plus_2.write_be(&mut scalar[..]).unwrap();
+    assert_eq!(&g1_msm(&[&g1[..], &scalar[..]].concat()).unwrap()[..], &vectors[0].2[..]);
+
+    let g2 = fps(&G2_HEX);
+    let p2 = fps(&P2_HEX);
+    let inf2 = vec![0u8; G2_LEN];
+
+    let vectors = [
+        (
+            "bls_g2mul_(g2+g2=2*g2)",
+            [&g2[..], &two[..]].concat(),
+            fps(&[
+                "1638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053",
+                "0a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577",
+                "0468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899",
+                "0f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf3",
+            ]),
+        ),
+        (
+            "bls_g2mul_(p2+p2=2*p2)",
+            [&p2[..], &two[..]].concat(),
+            fps(&[
+                "0b76fcbb604082a4f2d19858a7befd6053fa181c5119a612dfec83832537f644e02454f2b70d40985ebb08042d1620d4",
+                "19a4a02c0ae51365d964c73be7babb719db1c69e0ddbf9a8a335b5bed3b0a4b070d2d5df01d2da4a3f1e56aae2ec106d",
+                "0d18322f821ac72d3ca92f92b000483cf5b7d9e5d06873a44071c4e7e81efd904f210208fe0b9b4824f01c65bc7e6208",
+                "04e563d53609a2d1e216aaaee5fbc14ef460160db8d1fdc5e1bd4e8b54cd2f39abf6f925969fa405efb9e700b01c7085",
+            ]),
+        ),
+        ("bls_g2mul_(1*g2=g2)", [&g2[..], &one[..]].concat(), g2.clone()),
+        ("bls_g2mul_(1*p2=p2)", [&p2[..], &one[..]].concat(), p2.clone()),
+        ("bls_g2mul_(0*g2=inf)", [&g2[..], &zero[..]].concat(), inf2.clone()),
+        ("bls_g2mul_(x*inf=inf)", [&inf2[..], &random[..]].concat(), inf2.clone()),
+        (
+            "bls_g2mul_random*g2",
+            [&g2[..], &random[..]].concat(),
+            fps(&[
+                "14856c22d8cdb2967c720e963eedc999e738373b14172f06fc915769d3cc5ab7ae0a1b9c38f48b5585fb09d4bd2733bb",
+                "0c400b70f6f8cd35648f5c126cce5417f3be4d8eefbd42ceb4286a14df7e03135313fe5845e3a575faab3e8b949d2488",
+                "149a0aacc34beba2beb2f2a19a440166e76e373194714f108e4ab1c3fd331e80f4e73e6b9ea65fe3ec96d7136de81544",
+                "0e4622fef26bdb9b1e8ef6591a7cc99f5b73164500c1ee224b6a761e676b8799b09a3fd4fa7e242645cc1a34708285e4",
+            ]),
+        ),
+        (
+            "bls_g2mul_random*p2",
+            [&p2[..], &random[..]].concat(),
+            fps(&[
+                "036074dcbbd0e987531bfe0e45ddfbe09fd015665990ee0c352e8e403fe6af971d8f42141970d9ab14b4dd04874409e6",
+                "019705637f24ba2f398f32c3a3e20d6a1cd0fd63e6f8f071cf603a8334f255744927e7bfdfdb18519e019c49ff6e9145",
+                "008e74fcff4c4278c9accfb60809ed69bbcbe3d6213ef2304e078d15ec7d6decb4f462b24b8e7cc38cc11b6f2c9e0486",
+                "1331d40100f38c1070afd832445881b47cf4d63894666d9907c85ac66604aab5ad329980938cc3c167ccc5b6bc1b8f30",
+            ]),
+        ),
+        (
+            "random*g2+random*p2",
+            [&g2[..], &random[..], &p2[..], &random[..]].concat(),
+            fps(&[
+                "0a03efe8af5874d75f9db69feb8d0980ec0a6fc182765c3c22498e4c7c3080cd2093d259fdebcaabe8ba7459ec672ad0",
+                "139062ae4ee3b598d7e8010ea39fb759e49eaf4fc77bdd16e7a8ddb79fec10264fb19e90fa9747d45e9a93b2cf6a5b26",
+                "0a71e7e5315cfd98555369522b5cbea68875dd384a5ee3b7f160fa78388c48ebb844d90681069c11b353e61906536d1b",
+                "02e26f6f6a1d5daeea4e3057728099674396ef8bcd03a04be81db4bf75c0b50b563afc2101eef99e5ef304079eb45444",
+            ]),
+        ),
+    ];
+
+    for (name, input, output) in vectors.iter() {
+        assert_eq!(&g2_msm(input).unwrap()[..], &output[..], "{}", name);
+    }
+
+    let n = 5;
+    let g1s: Vec<_> = (0..n).map(|_| G1::rand(&mut rng).into_affine()).collect();
+    let g2s: Vec<_> = (0..n).map(|_| G2::rand(&mut rng).into_affine()).collect();
+    let scalars: Vec<_> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+
+    let mut g1_input = vec![];
+    let mut g2_input = vec![];
+    let mut g1_expected = G1Affine::zero().into_projective();
+    let mut g2_expected = G2Affine::zero().into_projective();
+    for i in 0..n {
+        let mut scalar = [0u8; SCALAR_LEN];
+        scalars[i].into_repr().write_be(&mut scalar[..]).unwrap();
+
+        g1_input.extend_from_slice(&encode_g1(&g1s[i]));
+        g1_input.extend_from_slice(&scalar);
+        g1_expected.add_assign(&g1s[i].mul(scalars[i]));
+
+        g2_input.extend_from_slice(&encode_g2(&g2s[i]));
+        g2_input.extend_from_slice(&scalar);
+        g2_expected.add_assign(&g2s[i].mul(scalars[i]));
+    }
+
+    assert_eq!(&g1_msm(&g1_input).unwrap()[..], &encode_g1(&g1_expected.into_affine())[..]);
+    assert_eq!(&g2_msm(&g2_input).unwrap()[..], &encode_g2(&g2_expected.into_affine())[..]);
+
+    match g1_msm(&[]) {
+        Err(PrecompileError::InvalidInputLength(0)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    match g2_msm(&g2_input[1..]) {
+        Err(PrecompileError::InvalidInputLength(_)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    // A point of E1 outside of G1
+    let p = g1_outside_subgroup();
+    match g1_msm(&[&encode_g1(&p)[..], &two[..]].concat()) {
+        Err(PrecompileError::InvalidPoint(GroupDecodingError::NotInSubgroup)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn test_pairing_check() {
+    let g1 = fps(&G1_HEX);
+    let g2 = fps(&G2_HEX);
+    let neg_g1 = fps(&[
+        G1_HEX[0],
+        "114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca",
+    ]);
+    let neg_g2 = fps(&[
+        G2_HEX[0],
+        G2_HEX[1],
+        "0d1b3cc2c7027888be51d9ef691d77bcb679afda66c73f17f9ee3837a55024f78c71363275a75d75d86bab79f74782aa",
+        "13fa4d4a0ad8b1ce186ed5061789213d993923066dddaf1040bc3ff59f825c78df74f2d75467e25e0f55f8a00fa030ed",
+    ]);
+    // 5 * G1, 7 * G2 and -35 * G1
+    let g1_5 = fps(&[
+        "10e7791fb972fe014159aa33a98622da3cdc98ff707965e536d8636b5fcc5ac7a91a8c46e59a00dca575af0f18fb13dc",
+        "16ba437edcc6551e30c10512367494bfb6b01cc6681e8a4c3cd2501832ab5c4abc40b4578b85cbaffbf0bcd70d67c6e2",
+    ]);
+    let g2_7 = fps(&[
+        "049cd1dbb2d2c3581e54c088135fef36505a6823d61b859437bfc79b617030dc8b40e32bad1fa85b9c0f368af6d38d3c",
+        "0d0273f6bf31ed37c3b8d68083ec3d8e20b5f2cc170fa24b9b5be35b34ed013f9a921f1cad1644d4bdb14674247234c8",
+        "08b7ae4dbf802c17a6648842922c9467e460a71c88d393ee7af356da123a2f3619e80c3bdcc8e2b1da52f8cd9913ccdd",
+        "05ecf93654b7a1885695aaeeb7caf41b0239dc45e1022be55d37111af2aecef87799638bec572de86a7437898efa7020",
+    ]);
+    let neg_g1_35 = fps(&[
+        "060d5589316a5e16e1d9bb03db45136afb9a3d6e97d350256129ee32a8e33396907dc44d2211762967d88d3e2840f71b",
+        "0a86a19dc09a3f29d4e2bf4524b97b3ecb478c8f6138000a29aa79a197bf3117274334a5986cc3c5d9efe75ff9dc2dd6",
+    ]);
+    let (inf1, inf2) = (vec![0u8; G1_LEN], vec![0u8; G2_LEN]);
+
+    let (zero, one) = (scalar("0"), scalar("1"));
+
+    // The cases of the pairing vectors of EIP-2537, on multiples of the
+    // generators
+    let vectors = [
+        ("non-degeneracy", [&g1[..], &g2[..]].concat(), zero.clone()),
+        ("e(G1,0)=e(0,G2)", [&g1[..], &inf2[..], &inf1[..], &g2[..]].concat(), one.clone()),
+        ("e(G1,G2)=e(G1,G2)", [&g1[..], &g2[..], &neg_g1[..], &g2[..]].concat(), one.clone()),
+        ("e(G1,-G2)=e(-G1,G2)", [&g1[..], &neg_g2[..], &g1[..], &g2[..]].concat(), one.clone()),
+        ("e(5G1,7G2)=e(35G1,G2)", [&g1_5[..], &g2_7[..], &neg_g1_35[..], &g2[..]].concat(), one.clone()),
+        ("e(5G1,7G2)!=e(G1,G2)", [&g1_5[..], &g2_7[..], &neg_g1[..], &g2[..]].concat(), zero.clone()),
+    ];
+
+    for (name, input, output) in vectors.iter() {
+        assert_eq!(&pairing_check(input).unwrap()[..], &output[..], "{}", name);
+    }
+
+    match pairing_check(&[]) {
+        Err(PrecompileError::InvalidInputLength(0)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    let q = g2_outside_subgroup();
+    match pairing_check(&[&g1[..], &encode_g2(&q)[..]].concat()) {
+        Err(PrecompileError::InvalidPoint(GroupDecodingError::NotInSubgroup)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn test_map() {
+    // The BLS12381G1_XMD:SHA-256_SSWU_NU_ vectors of RFC 9380, which EIP-2537
+    // reuses: u and the point it is mapped to
+    let vectors = [
+        (
+            "msg = \"\"",
+            fps(&["156c8a6a2c184569d69a76be144b5cdc5141d2d2ca4fe341f011e25e3969c55ad9e9b9ce2eb833c81a908e5fa4ac5f03"]),
+            fps(&[
+                "184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba",
+                "04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3",
+            ]),
+        ),
+        (
+            "msg = \"abc\"",
+            fps(&["147e1ed29f06e4c5079b9d14fc89d2820d32419b990c1c7bb7dbea2a36a045124b31ffbde7c99329c05c559af1c6cc82"]),
+            fps(&[
+                "009769f3ab59bfd551d53a5f846b9984c59b97d6842b20a2c565baa167945e3d026a3755b6345df8ec7e6acb6868ae6d",
+                "1532c00cf61aa3d0ce3e5aa20c3b531a2abd2c770a790a2613818303c6b830ffc0ecf6c357af3317b9575c567f11cd2c",
+            ]),
+        ),
+        (
+            "msg = \"abcdef0123456789\"",
+            fps(&["04090815ad598a06897dd89bcda860f25837d54e897298ce31e6947378134d3761dc59a572154963e8c954919ecfa82d"]),
+            fps(&[
+                "1974dbb8e6b5d20b84df7e625e2fbfecb2cdb5f77d5eae5fb2955e5ce7313cae8364bc2fff520a6c25619739c6bdcb6a",
+                "15f9897e11c6441eaa676de141c8d83c37aab8667173cbe1dfd6de74d11861b961dccebcd9d289ac633455dfcc7013a3",
+            ]),
+        ),
+    ];
+
+    for (name, input, output) in vectors.iter() {
+        assert_eq!(&map_fp_to_g1(input).unwrap()[..], &output[..], "{}", name);
+    }
+
+    // The BLS12381G2_XMD:SHA-256_SSWU_NU_ vector of RFC 9380 for msg = ""
+    let input = fps(&[
+        "07355d25caf6e7f2f0cb2812ca0e513bd026ed09dda65b177500fa31714e09ea0ded3a078b526bed3307f804d4b93b04",
+        "02829ce3c021339ccb5caf3e187f6370e1e2a311dec9b75363117063ab2015603ff52c3d3b98f19c2f65575e99e8b78c",
+    ]);
+    let output = fps(&[
+        "00e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb7",
+        "126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b",
+        "0caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42",
+        "1498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d",
+    ]);
+    assert_eq!(&map_fp2_to_g2(&input).unwrap()[..], &output[..]);
+
+    let mut input = [0u8; FP_LEN];
+    input[0] = 1;
+    match map_fp_to_g1(&input) {
+        Err(PrecompileError::InvalidFieldElement(_)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    match map_fp2_to_g2(&[0u8; FP_LEN]) {
+        Err(PrecompileError::InvalidInputLength(64)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
@@ -0,0 +1,221 @@
+use super::fq::{
+    ISO11_XDEN, ISO11_XNUM, ISO11_YDEN, ISO11_YNUM, ISO3_XDEN, ISO3_XNUM, ISO3_YDEN, ISO3_YNUM,
+    SSWU_ELLP_A, SSWU_ELLP_A2, SSWU_ELLP_B, SSWU_ELLP_B2, SSWU_Z, SSWU_Z2,
+};
+use super::{Fq, Fq2, G1, G1Affine, G2, G2Affine};
+use crate::hash_to_curve::{hash_to_field, isogeny_map, map_to_curve_simple_swu, Sgn0};
+use crate::{CurveAffine, CurveProjective, HashToCurve};
+
+use ff::{Field, PrimeField, PrimeFieldRepr};
+
+impl Sgn0 for Fq {
+    fn sgn0(&self) -> bool {
+        self.into_repr().is_odd()
+    }
+}
+
+impl Sgn0 for Fq2 {
+    fn sgn0(&self) -> bool {
+        let sign_0 = self.c0.sgn0();
+        let zero_0 = self.c0.is_zero();
+        let sign_1 = self.c1.sgn0();
+
+        sign_0 || (zero_0 && sign_1)
+    }
+}
+
+/// Maps a field element to E1' with the simplified SWU map, then to E1 with
+/// the 11-isogeny. The result is not yet in G1.
+pub(super) fn map_to_curve_g1(u: &Fq) -> G1 {
+    let (x, y) = map_to_curve_simple_swu(u, &SSWU_ELLP_A, &SSWU_ELLP_B, &SSWU_Z);
+
+    match isogeny_map(&x, &y, &ISO11_XNUM, &ISO11_XDEN, &ISO11_YNUM, &ISO11_YDEN) {
+        Some((x, y)) => G1Affine {
+            x,
+            y,
+            infinity: false,
+        }
+        .into_projective(),
+        None => G1::zero(),
+    }
+}
+
+/// Maps a field element to E2' with the simplified SWU map, then to E2 with
+/// the 3-isogeny. The result is not yet in G2.
+pub(super) fn map_to_curve_g2(u: &Fq2) -> G2 {
+    let (x, y) = map_to_curve_simple_swu(u, &SSWU_ELLP_A2, &SSWU_ELLP_B2, &SSWU_Z2);
+
+    match isogeny_map(&x, &y, &ISO3_XNUM, &ISO3_XDEN, &ISO3_YNUM, &ISO3_YDEN) {
+        Some((x, y)) => G2Affine {
+            x,
+            y,
+            infinity: false,
+        }
+        .into_projective(),
+        None => G2::zero(),
+    }
+}
+
+fn hash_to_fq2(msg: &[u8], dst: &[u8], count: usize) -> Vec<Fq2> {
+    hash_to_field::<Fq>(msg, dst, 2 * count)
+        .chunks(2)
+        .map(|c| Fq2 { c0: c[0], c1: c[1] })
+        .collect()
+}
+
+/// Implements the BLS12381G1_XMD:SHA-256_SSWU_RO_ and
+/// BLS12381G1_XMD:SHA-256_SSWU_NU_ suites.
+impl HashToCurve for G1 {
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        let u = hash_to_field::<Fq>(msg, dst, 2);
+
+        let mut q = map_to_curve_g1(&u[0]);
+        q.add_assign(&map_to_curve_g1(&u[1]));
+        q.clear_cofactor()
+    }
+
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        let u = hash_to_field::<Fq>(msg, dst, 1);
+
+        map_to_curve_g1(&u[0]).clear_cofactor()
+    }
+}
+
+/// Implements the BLS12381G2_XMD:SHA-256_SSWU_RO_ and
+/// BLS12381G2_XMD:SHA-256_SSWU_NU_ suites.
+impl HashToCurve for G2 {
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        let u = hash_to_fq2(msg, dst, 2);
+
+        let mut q = map_to_curve_g2(&u[0]);
+        q.add_assign(&map_to_curve_g2(&u[1]));
+        q.clear_cofactor()
+    }
+
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        let u = hash_to_fq2(msg, dst, 1);
+
+        map_to_curve_g2(&u[0]).clear_cofactor()
+    }
+}
+
+#[cfg(test)]
+fn fq(hex: &str) -> Fq {
+    Fq::from_hex(hex).unwrap()
+}
+
+#[test]
+fn test_g1_hash_to_curve_vectors() {
+    // BLS12381G1_XMD:SHA-256_SSWU_RO_ vectors from RFC 9380, appendix J.9.1
+    let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    let vectors: [(&[u8], &str, &str); 2] = [
+        (
+            b"",
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+        ),
+        (
+            b"abc",
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+            "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+        ),
+    ];
+
+    for &(msg, x, y) in vectors.iter() {
+        let p = G1::hash_to_curve(msg, dst).into_affine();
+        assert_eq!(p.x, fq(x));
+        assert_eq!(p.y, fq(y));
+    }
+}
+
+#[test]
+fn test_g1_encode_to_curve_vectors() {
+    // BLS12381G1_XMD:SHA-256_SSWU_NU_ vectors from RFC 9380, appendix J.9.2
+    let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_";
+    let vectors: [(&[u8], &str, &str); 2] = [
+        (
+            b"",
+            "184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba",
+            "04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3",
+        ),
+        (
+            b"abc",
+            "009769f3ab59bfd551d53a5f846b9984c59b97d6842b20a2c565baa167945e3d026a3755b6345df8ec7e6acb6868ae6d",
+            "1532c00cf61aa3d0ce3e5aa20c3b531a2abd2c770a790a2613818303c6b830ffc0ecf6c357af3317b9575c567f11cd2c",
+        ),
+    ];
+
+    for &(msg, x, y) in vectors.iter() {
+        let p = G1::encode_to_curve(msg, dst).into_affine();
+        assert_eq!(p.x, fq(x));
+        assert_eq!(p.y, fq(y));
+    }
+}
+
+#[test]
+fn test_g2_hash_to_curve_vectors() {
+    // BLS12381G2_XMD:SHA-256_SSWU_RO_ vectors from RFC 9380, appendix J.10.1
+    let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+    let vectors: [(&[u8], [&str; 4]); 2] = [
+        (
+            b"",
+            [
+                "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+                "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d",
+                "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+                "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+            ],
+        ),
+        (
+            b"abc",
+            [
+                "02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+                "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8",
+                "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+                "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16",
+            ],
+        ),
+    ];
+
+    for &(msg, [x0, x1, y0, y1]) in vectors.iter() {
+        let p = G2::hash_to_curve(msg, dst).into_affine();
+        assert_eq!(p.x.c0, fq(x0));
+        assert_eq!(p.x.c1, fq(x1));
+        assert_eq!(p.y.c0, fq(y0));
+        assert_eq!(p.y.c1, fq(y1));
+    }
+}
+
+#[test]
+fn test_g2_encode_to_curve_vectors() {
+    // BLS12381G2_XMD:SHA-256_SSWU_NU_ vectors from RFC 9380, appendix J.10.2
+    let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_NU_";
+    let vectors: [(&[u8], [&str; 4]); 2] = [
+        (
+            b"",
+            [
+                "00e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb7",
+                "126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b",
+                "0caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42",
+                "1498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d",
+            ],
+        ),
+        (
+            b"abc",
+            [
+                "108ed59fd9fae381abfd1d6bce2fd2fa220990f0f837fa30e0f27914ed6e1454db0d1ee957b219f61da6ff8be0d6441f",
+                "0296238ea82c6d4adb3c838ee3cb2346049c90b96d602d7bb1b469b905c9228be25c627bffee872def773d5b2a2eb57d",
+                "033f90f6057aadacae7963b0a0b379dd46750c1c94a6357c99b65f63b79e321ff50fe3053330911c56b6ceea08fee656",
+                "153606c417e59fb331b7ae6bce4fbf7c5190c33ce9402b5ebe2b70e44fca614f3f1382a3625ed5493843d0b0a652fc3f",
+            ],
+        ),
+    ];
+
+    for &(msg, [x0, x1, y0, y1]) in vectors.iter() {
+        let p = G2::encode_to_curve(msg, dst).into_affine();
+        assert_eq!(p.x.c0, fq(x0));
+        assert_eq!(p.x.c1, fq(x1));
+        assert_eq!(p.y.c0, fq(y0));
+        assert_eq!(p.y.c1, fq(y1));
+    }
+}
@@ -1,26 +1,36 @@
+pub mod eip2537;
 mod ec;
 mod fq;
 mod fq12;
 mod fq2;
 mod fq6;
 mod fr;
+mod hash_to_curve;
 
 #[cfg(test)]
 mod tests;
 
 pub use self::ec::{
     G1, G1Affine, G1Compressed, G1Prepared, G1Uncompressed, G2, G2Affine, G2Compressed, G2Prepared,
-    G2Uncompressed,
+    G2PreparedAffine, G2Uncompressed,
 };
 pub use self::fq::{Fq, FqRepr};
-pub use self::fq12::Fq12;
+pub use self::fq12::{CompressedFq12, Fq12};
 pub use self::fq2::Fq2;
 pub use self::fq6::Fq6;
 pub use self::fr::{Fr, FrRepr};
 
-use crate::{CurveAffine, Engine};
+use self::ec::G2PreparedLines;
+use crate::prepared::{
+    mismatched_coefficients, read_fq, read_header, read_len, read_point, write_fq, write_header,
+    write_len, write_point, AFFINE_LINES, PROJECTIVE_LINES,
+};
+use crate::{generics::fields::batch_inversion, parallel, CurveAffine, Engine, Gt};
 
 use ff::{BitIterator, Field, ScalarEngine};
+use std::io::{self, Read, Write};
+use std::slice;
+use std::sync::OnceLock;
 
 // The BLS parameter x for BLS12-381 is -0xd201000000010000
 const BLS_X: u64 = 0xd201000000010000;
@@ -41,6 +51,7 @@ impl Engine for Bls12 {
     type Fq = Fq;
     type Fqe = Fq2;
     type Fqk = Fq12;
+    type Gt = Gt<Self>;
 
     fn miller_loop<'a, I>(i: I) -> Self::Fqk
     where
@@ -54,56 +65,11 @@ impl Engine for Bls12 {
         let mut pairs = vec![];
         for &(p, q) in i {
             if !p.is_zero() && !q.is_zero() {
-                pairs.push((p, q.coeffs.iter()));
-            }
-        }
-
-        // Twisting isomorphism from E to E'
-        fn ell(f: &mut Fq12, coeffs: &(Fq2, Fq2, Fq2), p: &G1Affine) {
-            let mut c0 = coeffs.0;
-            let mut c1 = coeffs.1;
-
-            c0.c0.mul_assign(&p.y);
-            c0.c1.mul_assign(&p.y);
-
-            c1.c0.mul_assign(&p.x);
-            c1.c1.mul_assign(&p.x);
-
-            // Sparse multiplication in Fq12
-            f.mul_by_014(&coeffs.2, &c1, &c0);
-        }
-
-        let mut f = Fq12::one();
-
-        let mut found_one = false;
-        for i in BitIterator::new(&[BLS_X >> 1]) {
-            if !found_one {
-                found_one = i;
-                continue;
-            }
-
-            for &mut (p, ref mut coeffs) in &mut pairs {
-                ell(&mut f, coeffs.next().unwrap(), &p.0);
-            }
-
-            if i {
-                for &mut (p, ref mut coeffs) in &mut pairs {
-                    ell(&mut f, coeffs.next().unwrap(), &p.0);
-                }
+                pairs.push((p, Lines::new(&q.lines)));
             }
-
-            f.square();
-        }
-
-        for &mut (p, ref mut coeffs) in &mut pairs {
-            ell(&mut f, coeffs.next().unwrap(), &p.0);
-        }
-
-        if BLS_X_IS_NEGATIVE {
-            f.conjugate();
         }
 
-        f
+        parallel::product(pairs, 4, Self::miller_loop_lines)
     }
 
     fn final_exponentiation(r: &Fq12) -> Option<Fq12> {
@@ -119,7 +85,7 @@ impl Engine for Bls12 {
                 r.mul_assign(&f2);
 
                 fn exp_by_x(f: &mut Fq12, x: u64) {
-                    *f = f.pow(&[x]);
+                    *f = f.compressed_cyclotomic_exp(&[x]);
                     if BLS_X_IS_NEGATIVE {
                         f.conjugate();
                     }
@@ -163,6 +129,112 @@ impl Engine for Bls12 {
             None => None,
         }
     }
+
+    fn gt_generator() -> Fq12 {
+        static GENERATOR: OnceLock<Fq12> = OnceLock::new();
+
+        *GENERATOR.get_or_init(|| Self::pairing(G1Affine::one(), G2Affine::one()))
+    }
+}
+
+impl Bls12 {
+    /// Performs a Miller loop over `G2PreparedAffine` elements, which is
+    /// what `Engine::miller_loop` does for `G2Prepared` elements converted
+    /// from them.
+    pub fn miller_loop_affine<'a, I>(i: I) -> Fq12
+    where
+        I: IntoIterator<Item = &'a (&'a G1Prepared, &'a G2PreparedAffine)>,
+    {
+        let mut pairs = vec![];
+        for &(p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs.push((p, Lines::Affine(q.coeffs.iter())));
+            }
+        }
+
+        parallel::product(pairs, 4, Self::miller_loop_lines)
+    }
+
+    fn miller_loop_lines(mut pairs: Vec<(&G1Prepared, Lines)>) -> Fq12 {
+        let mut f = Fq12::one();
+
+        let mut found_one = false;
+        for i in BitIterator::new(&[BLS_X >> 1]) {
+            if !found_one {
+                found_one = i;
+                continue;
+            }
+
+            for &mut (p, ref mut lines) in &mut pairs {
+                lines.ell(&mut f, &p.0);
+            }
+
+            if i {
+                for &mut (p, ref mut lines) in &mut pairs {
+                    lines.ell(&mut f, &p.0);
+                }
+            }
+
+            f.square();
+        }
+
+        for &mut (p, ref mut lines) in &mut pairs {
+            lines.ell(&mut f, &p.0);
+        }
+
+        if BLS_X_IS_NEGATIVE {
+            f.conjugate();
+        }
+
+        f
+    }
+}
+
+/// The remaining lines of a prepared element of G2, in either form.
+enum Lines<'a> {
+    Projective(slice::Iter<'a, (Fq2, Fq2, Fq2)>),
+    Affine(slice::Iter<'a, (Fq2, Fq2)>),
+}
+
+impl<'a> Lines<'a> {
+    fn new(lines: &'a G2PreparedLines) -> Self {
+        match *lines {
+            G2PreparedLines::Projective(ref coeffs) => Lines::Projective(coeffs.iter()),
+            G2PreparedLines::Affine(ref coeffs) => Lines::Affine(coeffs.iter()),
+        }
+    }
+
+    /// Multiplies `f` by the next line evaluated at `p`.
+    fn ell(&mut self, f: &mut Fq12, p: &G1Affine) {
+        // Twisting isomorphism from E to E'
+        match *self {
+            Lines::Projective(ref mut coeffs) => {
+                let coeffs = coeffs.next().unwrap();
+                let mut c0 = coeffs.0;
+                let mut c1 = coeffs.1;
+
+                c0.c0.mul_assign(&p.y);
+                c0.c1.mul_assign(&p.y);
+
+                c1.c0.mul_assign(&p.x);
+                c1.c1.mul_assign(&p.x);
+
+                // Sparse multiplication in Fq12
+                f.mul_by_014(&coeffs.2, &c1, &c0);
+            }
+            Lines::Affine(ref mut coeffs) => {
+                let &(a, b) = coeffs.next().unwrap();
+                let mut c0 = Fq2::zero();
+                c0.c0 = p.y;
+                let mut c1 = a;
+
+                c1.c0.mul_assign(&p.x);
+                c1.c1.mul_assign(&p.x);
+
+                f.mul_by_014(&b, &c1, &c0);
+            }
+        }
+    }
 }
 
 impl G2Prepared {
@@ -173,7 +245,8 @@ impl G2Prepared {
     pub fn from_affine(q: G2Affine) -> Self {
         if q.is_zero() {
             return G2Prepared {
-                coeffs: vec![],
+                p: q,
+                lines: G2PreparedLines::Projective(vec![]),
                 infinity: true,
             };
         }
@@ -357,13 +430,245 @@ impl G2Prepared {
         coeffs.push(doubling_step(&mut r));
 
         G2Prepared {
+            p: q,
+            lines: G2PreparedLines::Projective(coeffs),
+            infinity: false,
+        }
+    }
+}
+
+impl G2PreparedAffine {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn from_affine(q: G2Affine) -> Self {
+        let coeffs = match G2Prepared::from_affine(q) {
+            G2Prepared {
+                lines: G2PreparedLines::Projective(coeffs),
+                infinity: false,
+                ..
+            } => coeffs,
+            _ => {
+                return G2PreparedAffine {
+                    p: q,
+                    coeffs: vec![],
+                    infinity: true,
+                }
+            }
+        };
+
+        // Each projective line is a multiple of y + a * x + b by its
+        // coefficient of y, which we invert all at once.
+        let mut scales: Vec<Fq2> = coeffs.iter().map(|c| c.0).collect();
+        batch_inversion(&mut scales);
+
+        let coeffs = coeffs
+            .iter()
+            .zip(scales.iter())
+            .map(|(&(_, mut a, mut b), scale)| {
+                a.mul_assign(scale);
+                b.mul_assign(scale);
+                (a, b)
+            })
+            .collect();
+
+        G2PreparedAffine {
+            p: q,
             coeffs,
             infinity: false,
         }
     }
 }
 
+impl From<G2PreparedAffine> for G2Prepared {
+    fn from(q: G2PreparedAffine) -> Self {
+        G2Prepared {
+            p: q.p,
+            lines: G2PreparedLines::Affine(q.coeffs),
+            infinity: q.infinity,
+        }
+    }
+}
+
+impl G2Prepared {
+    /// Writes a versioned encoding of this element, which `read` accepts.
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        match self.lines {
+            G2PreparedLines::Projective(ref coeffs) => {
+                write_header(&mut writer, PROJECTIVE_LINES)?;
+                write_point(&mut writer, &self.p)?;
+                write_len(&mut writer, coeffs.len())?;
+                for (c0, c1, c2) in coeffs {
+                    write_fq2(&mut writer, c0)?;
+                    write_fq2(&mut writer, c1)?;
+                    write_fq2(&mut writer, c2)?;
+                }
+            }
+            G2PreparedLines::Affine(ref coeffs) => {
+                write_header(&mut writer, AFFINE_LINES)?;
+                write_point(&mut writer, &self.p)?;
+                write_len(&mut writer, coeffs.len())?;
+                for (a, b) in coeffs {
+                    write_fq2(&mut writer, a)?;
+                    write_fq2(&mut writer, b)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads an element written by `write`. The point must be in G2 and the
+    /// lines must be the ones computed when preparing it, which is checked by
+    /// preparing it again.
+    pub fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        let form = read_header(&mut reader)?;
+        let p: G2Affine = read_point(&mut reader)?;
+
+        let count = if p.is_zero() { 0 } else { prepared_line_count() };
+        read_len(&mut reader, count)?;
+
+        let lines = if form == PROJECTIVE_LINES {
+            let mut coeffs = Vec::with_capacity(count);
+            for _ in 0..count {
+                coeffs.push((
+                    read_fq2(&mut reader)?,
+                    read_fq2(&mut reader)?,
+                    read_fq2(&mut reader)?,
+                ));
+            }
+
+            G2PreparedLines::Projective(coeffs)
+        } else {
+            let mut coeffs = Vec::with_capacity(count);
+            for _ in 0..count {
+                coeffs.push((read_fq2(&mut reader)?, read_fq2(&mut reader)?));
+            }
+
+            G2PreparedLines::Affine(coeffs)
+        };
+
+        let res = G2Prepared {
+            p,
+            lines,
+            infinity: p.is_zero(),
+        };
+
+        let expected = if form == PROJECTIVE_LINES {
+            p.prepare()
+        } else {
+            G2Prepared::from(G2PreparedAffine::from_affine(p))
+        };
+        if res != expected {
+            return Err(mismatched_coefficients());
+        }
+
+        Ok(res)
+    }
+}
+
+/// Returns the number of lines of a prepared element of G2 other than zero.
+fn prepared_line_count() -> usize {
+    let mut count = 1;
+
+    let mut found_one = false;
+    for i in BitIterator::new([BLS_X >> 1]) {
+        if !found_one {
+            found_one = i;
+            continue;
+        }
+
+        count += if i { 2 } else { 1 };
+    }
+
+    count
+}
+
+fn write_fq2<W: Write>(writer: &mut W, c: &Fq2) -> io::Result<()> {
+    write_fq(writer, &c.c1)?;
+    write_fq(writer, &c.c0)
+}
+
+fn read_fq2<R: Read>(reader: &mut R) -> io::Result<Fq2> {
+    let c1 = read_fq(reader)?;
+    let c0 = read_fq(reader)?;
+
+    Ok(Fq2 { c0, c1 })
+}
+
+#[cfg(test)]
+impl crate::tests::engine::AffineMillerLoop for Bls12 {
+    type G2PreparedAffine = G2PreparedAffine;
+
+    fn prepare_affine(q: G2Affine) -> G2PreparedAffine {
+        G2PreparedAffine::from_affine(q)
+    }
+
+    fn is_zero(q: &G2PreparedAffine) -> bool {
+        q.is_zero()
+    }
+
+    fn into_prepared(q: G2PreparedAffine) -> G2Prepared {
+        G2Prepared::from(q)
+    }
+
+    fn miller_loop_affine(i: &[(&G1Prepared, &G2PreparedAffine)]) -> Fq12 {
+        Bls12::miller_loop_affine(i)
+    }
+}
+
+#[test]
+fn test_g2_prepared_affine() {
+    crate::tests::engine::affine_miller_loop_tests::<Bls12>();
+}
+
+#[test]
+fn test_prepared_encoding() {
+    use crate::{CurveProjective, EncodedPoint};
+    use rand::{Rand, SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    let q = G2::rand(&mut rng).into_affine();
+    let other = G2::rand(&mut rng).into_affine();
+
+    for prepared in vec![
+        q.prepare(),
+        G2Prepared::from(G2PreparedAffine::from_affine(q)),
+        G2Affine::zero().prepare(),
+    ] {
+        let mut encoded = vec![];
+        prepared.write(&mut encoded).unwrap();
+        assert_eq!(G2Prepared::read(&encoded[..]).unwrap(), prepared);
+
+        // an unknown version
+        let mut bad = encoded.clone();
+        bad[0] = 2;
+        assert!(G2Prepared::read(&bad[..]).is_err());
+
+        // truncated
+        assert!(G2Prepared::read(&encoded[..encoded.len() - 1]).is_err());
+
+        // the lines of one point next to another
+        let mut bad = encoded.clone();
+        bad[2..2 + G2Uncompressed::size()].copy_from_slice(other.into_uncompressed().as_ref());
+        assert!(G2Prepared::read(&bad[..]).is_err());
+
+        // a corrupted last line
+        let mut bad = encoded.clone();
+        *bad.last_mut().unwrap() ^= 1;
+        assert!(G2Prepared::read(&bad[..]).is_err());
+    }
+}
+
 #[test]
 fn bls12_engine_tests() {
     crate::tests::engine::engine_tests::<Bls12>();
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn bls12_serde_tests() {
+    crate::tests::serialization::serde_tests::<Bls12>();
+}
@@ -84,7 +84,111 @@ macro_rules! curve_impl {
             }
         }
 
+        impl ConditionallySelectable for $affine {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                $affine {
+                    x: $basefield::conditional_select(&a.x, &b.x, choice),
+                    y: $basefield::conditional_select(&a.y, &b.y, choice),
+                    infinity: u8::conditional_select(&(a.infinity as u8), &(b.infinity as u8), choice) != 0,
+                }
+            }
+        }
+
+        impl ConstantTimeEq for $affine {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                let self_is_zero = Choice::from(self.infinity as u8);
+                let other_is_zero = Choice::from(other.infinity as u8);
+
+                (self_is_zero & other_is_zero)
+                    | (!self_is_zero & !other_is_zero & self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y))
+            }
+        }
+
+        impl CtCurveAffine for $affine {
+            fn conditional_negate(&mut self, choice: Choice) {
+                // the point at infinity keeps its y coordinate, as in `negate`
+                let infinity = Choice::from(self.infinity as u8);
+                self.y.conditional_negate(choice & !infinity);
+            }
+
+            fn ct_mul<S: Into<<Self::Scalar as PrimeField>::Repr>>(&self, scalar: S) -> Self {
+                let (x, y, infinity) = crate::ct::mul(
+                    &self.x,
+                    &self.y,
+                    Choice::from(self.infinity as u8),
+                    &$basefield::zero(),
+                    &$affine::get_coeff_b(),
+                    scalar.into(),
+                );
+
+                $affine::conditional_select(&$affine { x, y, infinity: false }, &$affine::zero(), infinity)
+            }
+        }
+
+        impl ConditionallySelectable for $projective {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                $projective {
+                    x: $basefield::conditional_select(&a.x, &b.x, choice),
+                    y: $basefield::conditional_select(&a.y, &b.y, choice),
+                    z: $basefield::conditional_select(&a.z, &b.z, choice),
+                }
+            }
+        }
+
+        impl ConstantTimeEq for $projective {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                // The same comparison as `eq`, without returning early.
+                let mut z1 = self.z;
+                z1.square();
+                let mut z2 = other.z;
+                z2.square();
+
+                let mut x1 = self.x;
+                x1.mul_assign(&z2);
+                let mut x2 = other.x;
+                x2.mul_assign(&z1);
+
+                z1.mul_assign(&self.z);
+                z2.mul_assign(&other.z);
+
+                let mut y1 = self.y;
+                y1.mul_assign(&z2);
+                let mut y2 = other.y;
+                y2.mul_assign(&z1);
+
+                let self_is_zero = self.z.ct_eq(&$basefield::zero());
+                let other_is_zero = other.z.ct_eq(&$basefield::zero());
+
+                (self_is_zero & other_is_zero)
+                    | (!self_is_zero & !other_is_zero & x1.ct_eq(&x2) & y1.ct_eq(&y2))
+            }
+        }
+
+        impl CtCurveProjective for $projective {
+            fn conditional_negate(&mut self, choice: Choice) {
+                self.y.conditional_negate(choice);
+            }
+
+            fn ct_into_affine(&self) -> $affine {
+                let z_inv = self.z.ct_inverse();
+                let infinity = z_inv.is_none();
+                let z_inv = z_inv.unwrap_or($basefield::zero());
+
+                // (X / Z^2, Y / Z^3)
+                let mut z_inv2 = z_inv;
+                z_inv2.square();
+                let mut x = self.x;
+                x.mul_assign(&z_inv2);
+                z_inv2.mul_assign(&z_inv);
+                let mut y = self.y;
+                y.mul_assign(&z_inv2);
+
+                $affine::conditional_select(&$affine { x, y, infinity: false }, &$affine::zero(), infinity)
+            }
+        }
+
         impl $affine {
+            #[allow(dead_code)]
             fn mul_bits<S: AsRef<[u64]>>(&self, bits: BitIterator<S>) -> $projective {
                 let mut res = $projective::zero();
                 for i in bits {
@@ -99,7 +203,7 @@ macro_rules! curve_impl {
             ///
             /// If and only if `greatest` is set will the lexicographically
             /// largest y-coordinate be selected.
-            fn get_point_from_x(x: $basefield, greatest: bool) -> Option<$affine> {
+            pub(crate) fn get_point_from_x(x: $basefield, greatest: bool) -> Option<$affine> {
                 // Compute x^3 + b
                 let mut x3b = x;
                 x3b.square();
@@ -122,23 +226,93 @@ macro_rules! curve_impl {
                 })
             }
 
-            fn is_on_curve(&self) -> bool {
-                if self.is_zero() {
-                    true
+            /// Constructs a point from its affine coordinates, checking that it
+            /// is on the curve and in the prime order subgroup. The coordinates
+            /// (0, 0) denote the point at infinity.
+            pub fn from_xy_checked(x: $basefield, y: $basefield) -> Result<Self, GroupDecodingError> {
+                let p = Self::from_xy_unchecked(x, y);
+
+                if !p.is_on_curve() {
+                    Err(GroupDecodingError::NotOnCurve)
+                } else if !p.is_in_correct_subgroup_assuming_on_curve() {
+                    Err(GroupDecodingError::NotInSubgroup)
                 } else {
-                    // Check that the point is on the curve
-                    let mut y2 = self.y;
-                    y2.square();
+                    Ok(p)
+                }
+            }
 
-                    let mut x3b = self.x;
-                    x3b.square();
-                    x3b.mul_assign(&self.x);
-                    x3b.add_assign(&Self::get_coeff_b());
+            /// Constructs a point from its affine coordinates without checking
+            /// that it is on the curve or in the prime order subgroup. The
+            /// coordinates (0, 0) denote the point at infinity.
+            ///
+            /// If the point is invalid, this can break API invariants, so
+            /// caution is strongly encouraged.
+            pub fn from_xy_unchecked(x: $basefield, y: $basefield) -> Self {
+                if x.is_zero() && y.is_zero() {
+                    Self::zero()
+                } else {
+                    $affine {
+                        x,
+                        y,
+                        infinity: false
+                    }
+                }
+            }
 
-                    y2 == x3b
+            /// Constructs the point with the given x-coordinate, checking that
+            /// it is in the prime order subgroup. If and only if `greatest` is
+            /// set will the lexicographically largest y-coordinate be selected,
+            /// as with the sign flag of the compressed encoding.
+            pub fn from_x_and_sign(x: $basefield, greatest: bool) -> Result<Self, GroupDecodingError> {
+                let p = Self::get_point_from_x(x, greatest).ok_or(GroupDecodingError::NotOnCurve)?;
+
+                if p.is_in_correct_subgroup_assuming_on_curve() {
+                    Ok(p)
+                } else {
+                    Err(GroupDecodingError::NotInSubgroup)
+                }
+            }
+
+            /// Returns the x-coordinate, or zero for the point at infinity.
+            pub fn x(&self) -> $basefield {
+                if self.infinity {
+                    $basefield::zero()
+                } else {
+                    self.x
+                }
+            }
+
+            /// Returns the y-coordinate, or zero for the point at infinity.
+            pub fn y(&self) -> $basefield {
+                if self.infinity {
+                    $basefield::zero()
+                } else {
+                    self.y
                 }
             }
+        }
+
+        #[cfg(test)]
+        impl crate::tests::curve::XyConstructors for $affine {
+            fn x(&self) -> $basefield {
+                $affine::x(self)
+            }
+
+            fn y(&self) -> $basefield {
+                $affine::y(self)
+            }
 
+            fn from_xy_checked(x: $basefield, y: $basefield) -> Result<Self, GroupDecodingError> {
+                $affine::from_xy_checked(x, y)
+            }
+
+            fn from_xy_unchecked(x: $basefield, y: $basefield) -> Self {
+                $affine::from_xy_unchecked(x, y)
+            }
+
+            fn from_x_and_sign(x: $basefield, greatest: bool) -> Result<Self, GroupDecodingError> {
+                $affine::from_x_and_sign(x, greatest)
+            }
         }
 
         impl CurveAffine for $affine {
@@ -190,8 +364,51 @@ macro_rules! curve_impl {
             fn into_projective(&self) -> $projective {
                 (*self).into()
             }
+
+            fn is_on_curve(&self) -> bool {
+                if self.is_zero() {
+                    true
+                } else {
+                    // Check that the point is on the curve
+                    let mut y2 = self.y;
+                    y2.square();
+
+                    let mut x3b = self.x;
+                    x3b.square();
+                    x3b.mul_assign(&self.x);
+                    x3b.add_assign(&Self::get_coeff_b());
+
+                    y2 == x3b
+                }
+            }
+
+            fn is_in_subgroup(&self) -> bool {
+                self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve()
+            }
         }
        
+        impl $projective {
+            fn mul_bits<S: AsRef<[u64]>>(&self, bits: BitIterator<S>) -> $projective {
+                let mut res = $projective::zero();
+
+                let mut found_one = false;
+
+                for i in bits {
+                    if found_one {
+                        res.double();
+                    } else {
+                        found_one = i;
+                    }
+
+                    if i {
+                        res.add_assign(self);
+                    }
+                }
+
+                res
+            }
+        }
+
         impl CurveProjective for $projective {
             type Engine = Bn256;
             type Scalar = $scalarfield;
@@ -224,52 +441,56 @@ macro_rules! curve_impl {
 
             fn batch_normalization(v: &mut [Self])
             {
-                // Montgomery’s Trick and Fast Implementation of Masked AES
-                // Genelle, Prouff and Quisquater
-                // Section 3.2
-
-                // First pass: compute [a, ab, abc, ...]
-                let mut prod = Vec::with_capacity(v.len());
-                let mut tmp = $basefield::one();
-                for g in v.iter_mut()
-                          // Ignore normalized elements
-                          .filter(|g| !g.is_normalized())
-                {
-                    tmp.mul_assign(&g.z);
-                    prod.push(tmp);
-                }
+                // Chunks are normalized independently, on several threads with the
+                // `parallel` feature.
+                crate::parallel::for_each_chunk(v, 1024, |v| {
+                    // Montgomery’s Trick and Fast Implementation of Masked AES
+                    // Genelle, Prouff and Quisquater
+                    // Section 3.2
+
+                    // First pass: compute [a, ab, abc, ...]
+                    let mut prod = Vec::with_capacity(v.len());
+                    let mut tmp = $basefield::one();
+                    for g in v.iter_mut()
+                              // Ignore normalized elements
+                              .filter(|g| !g.is_normalized())
+                    {
+                        tmp.mul_assign(&g.z);
+                        prod.push(tmp);
+                    }
 
-                // Invert `tmp`.
-                tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-                // Second pass: iterate backwards to compute inverses
-                for (g, s) in v.iter_mut()
-                               // Backwards
-                               .rev()
-                               // Ignore normalized elements
-                               .filter(|g| !g.is_normalized())
-                               // Backwards, skip last element, fill in one for last term.
-                               .zip(prod.into_iter().rev().skip(1).chain(Some($basefield::one())))
-                {
-                    // tmp := tmp * g.z; g.z := tmp * s = 1/z
-                    let mut newtmp = tmp;
-                    newtmp.mul_assign(&g.z);
-                    g.z = tmp;
-                    g.z.mul_assign(&s);
-                    tmp = newtmp;
-                }
+                    // Invert `tmp`.
+                    tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
+
+                    // Second pass: iterate backwards to compute inverses
+                    for (g, s) in v.iter_mut()
+                                   // Backwards
+                                   .rev()
+                                   // Ignore normalized elements
+                                   .filter(|g| !g.is_normalized())
+                                   // Backwards, skip last element, fill in one for last term.
+                                   .zip(prod.into_iter().rev().skip(1).chain(Some($basefield::one())))
+                    {
+                        // tmp := tmp * g.z; g.z := tmp * s = 1/z
+                        let mut newtmp = tmp;
+                        newtmp.mul_assign(&g.z);
+                        g.z = tmp;
+                        g.z.mul_assign(&s);
+                        tmp = newtmp;
+                    }
 
-                // Perform affine transformations
-                for g in v.iter_mut()
-                          .filter(|g| !g.is_normalized())
-                {
-                    let mut z = g.z; // 1/z
-                    z.square(); // 1/z^2
-                    g.x.mul_assign(&z); // x/z^2
-                    z.mul_assign(&g.z); // 1/z^3
-                    g.y.mul_assign(&z); // y/z^3
-                    g.z = $basefield::one(); // z = 1
-                }
+                    // Perform affine transformations
+                    for g in v.iter_mut()
+                              .filter(|g| !g.is_normalized())
+                    {
+                        let mut z = g.z; // 1/z
+                        z.square(); // 1/z^2
+                        g.x.mul_assign(&z); // x/z^2
+                        z.mul_assign(&g.z); // 1/z^3
+                        g.y.mul_assign(&z); // y/z^3
+                        g.z = $basefield::one(); // z = 1
+                    }
+                });
             }
 
             fn double(&mut self) {
@@ -516,30 +737,17 @@ macro_rules! curve_impl {
             }
 
             fn mul_assign<S: Into<<Self::Scalar as PrimeField>::Repr>>(&mut self, other: S) {
-                let mut res = Self::zero();
-
-                let mut found_one = false;
-
-                for i in BitIterator::new(other.into())
-                {
-                    if found_one {
-                        res.double();
-                    } else {
-                        found_one = i;
-                    }
-
-                    if i {
-                        res.add_assign(self);
-                    }
-                }
-
-                *self = res;
+                *self = self.mul_bits(BitIterator::new(other.into()));
             }
 
             fn into_affine(&self) -> $affine {
                 (*self).into()
             }
 
+            fn clear_cofactor(&self) -> Self {
+                self.mul_by_effective_cofactor()
+            }
+
             fn recommended_wnaf_for_scalar(scalar: <Self::Scalar as PrimeField>::Repr) -> usize {
                 Self::empirical_recommended_wnaf_for_scalar(scalar)
             }
@@ -549,6 +757,15 @@ macro_rules! curve_impl {
             }
         }
 
+        // Points are (de)serialized through their compressed encoding, which
+        // checks subgroup membership when decoding.
+        #[cfg(feature = "serde")]
+        serde_impl!(
+            $affine,
+            crate::serialization::serialize_point,
+            crate::serialization::deserialize_point
+        );
+
         // The affine point X, Y is represented in the jacobian
         // coordinates with Z = 1.
         impl From<$affine> for $projective {
@@ -605,10 +822,13 @@ macro_rules! curve_impl {
 }
 
 pub mod g1 {
+    use super::super::fq::BETA;
     use super::super::{Bn256, Fq, Fq12, FqRepr, Fr, FrRepr};
     use super::g2::G2Affine;
+    use crate::glv::{glv_mul, GlvParameters};
     use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
     use rand::{Rand, Rng};
+    use crate::ct::{Choice, ConditionallySelectable, ConstantTimeEq, CtCurveAffine, CtCurveProjective, CtField};
     use std::fmt;
     use crate::{RawEncodable, CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
 
@@ -919,11 +1139,33 @@ pub mod g1 {
         }
     }
 
+    // The GLV decomposition for sigma.
+    const GLV_PARAMETERS: GlvParameters = GlvParameters {
+        basis: &[
+            &[(false, 0x89d3256894d213e3), (true, 0x6f4d8248eeb859fc8211bbeb7d4f1128)],
+            &[(false, 0x6f4d8248eeb859fd0be4e1541221250b), (false, 0x89d3256894d213e3)],
+        ],
+        g: &[
+            (false, &[0xd91d232ec7e0b3d7, 0x2]),
+            (false, &[0x7a7bd9d4391eb18d, 0x4ccef014a773d2cf, 0x2]),
+        ],
+    };
+
     impl G1Affine {
+        /// Multiplies by `k` with the GLV method. See `G1::mul_glv`.
+        pub fn mul_glv<S: Into<FrRepr>>(&self, k: S) -> G1 {
+            self.into_projective().mul_glv(k)
+        }
+
         // fn scale_by_cofactor(&self) -> G1 {
         //     self.into_projective()
         // }
 
+        /// G1 has cofactor one, so every point on the curve is in it.
+        fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+            true
+        }
+
         fn get_generator() -> Self {
             G1Affine {
                 x: super::super::fq::G1_GENERATOR_X,
@@ -942,6 +1184,27 @@ pub mod g1 {
     }
 
     impl G1 {
+        /// G1 has cofactor one, so every point on the curve is already in it.
+        fn mul_by_effective_cofactor(&self) -> G1 {
+            *self
+        }
+
+        /// Computes the endomorphism sigma(x, y) = (beta * x, y), which acts
+        /// on G1 as multiplication by lambda.
+        fn sigma(&self) -> G1 {
+            let mut res = *self;
+            res.x.mul_assign(&BETA);
+            res
+        }
+
+        /// Multiplies by `k` with the GLV method, which is faster than
+        /// `mul_assign`. The result is only correct for points on the curve,
+        /// so points built with `from_xy_unchecked` or decoded without checks
+        /// must use `mul_assign`.
+        pub fn mul_glv<S: Into<FrRepr>>(&self, k: S) -> G1 {
+            glv_mul(self, k.into(), &GLV_PARAMETERS, G1::sigma)
+        }
+
         fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
             let num_bits = scalar.num_bits() as usize;
 
@@ -1035,18 +1298,56 @@ pub mod g1 {
         print!("{}\n\n", a);
     }
 
+    #[test]
+    fn test_glv_decomposition() {
+        use crate::glv::glv_decompose;
+        use rand::{SeedableRng, XorShiftRng};
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let mut minus_one = Fr::one();
+        minus_one.negate();
+
+        let mut scalars = vec![Fr::zero(), Fr::one(), minus_one];
+        scalars.extend((0..1000).map(|_| Fr::rand(&mut rng)));
+
+        for k in scalars {
+            for (_, k_i) in glv_decompose::<Fr>(k.into_repr(), &GLV_PARAMETERS) {
+                assert!(k_i.num_bits() <= 129);
+            }
+        }
+    }
+
+    #[test]
+    fn test_g1_mul_glv() {
+        crate::tests::curve::endomorphism_mul_tests::<G1, _>(|p, k| p.mul_glv(k));
+        crate::tests::curve::endomorphism_mul_tests::<G1, _>(|p, k| p.into_affine().mul_glv(k));
+    }
+
     #[test]
     fn g1_curve_tests() {
         crate::tests::curve::curve_tests::<G1>();
         crate::tests::curve::random_transformation_tests::<G1>();
     }
+
+    #[test]
+    fn g1_ct_tests() {
+        crate::tests::curve::ct_tests::<G1>();
+    }
+
+    #[test]
+    fn test_g1_xy_constructors() {
+        crate::tests::curve::xy_constructor_tests::<G1>();
+    }
 }
 
 pub mod g2 {
     use super::super::{Bn256, Fq, Fq12, Fq2, FqRepr, Fr, FrRepr};
     use super::g1::G1Affine;
+    use crate::glv::{glv_mul, GlvParameters};
     use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
     use rand::{Rand, Rng};
+    use crate::ct::{Choice, ConditionallySelectable, ConstantTimeEq, CtCurveAffine, CtCurveProjective, CtField};
     use std::fmt;
     use crate::{CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
 
@@ -1121,6 +1422,8 @@ pub mod g2 {
 
             if !affine.is_on_curve() {
                 Err(GroupDecodingError::NotOnCurve)
+            } else if !affine.is_in_correct_subgroup_assuming_on_curve() {
+                Err(GroupDecodingError::NotInSubgroup)
             } else {
                 Ok(affine)
             }
@@ -1239,8 +1542,12 @@ pub mod g2 {
             let affine = self.into_affine_unchecked()?;
 
             // NB: Decompression guarantees that it is on the curve already.
-            
-            Ok(affine)
+
+            if !affine.is_in_correct_subgroup_assuming_on_curve() {
+                Err(GroupDecodingError::NotInSubgroup)
+            } else {
+                Ok(affine)
+            }
         }
         fn into_affine_unchecked(&self) -> Result<G2Affine, GroupDecodingError> {
             // Create a copy of this representation.
@@ -1317,8 +1624,31 @@ pub mod g2 {
         }
     }
 
+    // The GLS decomposition for psi, which acts on G2 as multiplication by
+    // 6u^2, with the lattice basis (-2u - 1, 0, -2u, -1), (2u, u + 1, -u, u),
+    // (u + 1, u, u, -2u), (2u + 1, -u, -u - 1, -u).
+    const GLS_PARAMETERS: GlvParameters = GlvParameters {
+        basis: &[
+            &[(true, 0x89d3256894d213e3), (false, 0x0), (true, 0x89d3256894d213e2), (true, 0x1)],
+            &[(false, 0x89d3256894d213e2), (false, 0x44e992b44a6909f2), (true, 0x44e992b44a6909f1), (false, 0x44e992b44a6909f1)],
+            &[(false, 0x44e992b44a6909f2), (false, 0x44e992b44a6909f1), (false, 0x44e992b44a6909f1), (true, 0x89d3256894d213e2)],
+            &[(false, 0x89d3256894d213e3), (true, 0x44e992b44a6909f1), (true, 0x44e992b44a6909f2), (true, 0x44e992b44a6909f1)],
+        ],
+        g: &[
+            (true, &[0x2dff291532e42728, 0x55b4ca7ba3e5577f, 0x9e80318ab0d92b95]),
+            (false, &[0x46f4bda995d51bb1, 0x8e5da66fc7184ae, 0x9e80318ab0d92b93]),
+            (false, &[0xd91d232ec7e0b3d7, 0x2]),
+            (false, &[0xc170977dcef3cd3f, 0x55b4ca7ba3e5577d, 0x9e80318ab0d92b95]),
+        ],
+    };
+
     impl G2Affine {
-        fn scale_by_cofactor(&self) -> G2 {
+        /// Multiplies by `k` with the GLS method. See `G2::mul_gls`.
+        pub fn mul_gls<S: Into<FrRepr>>(&self, k: S) -> G2 {
+            self.into_projective().mul_gls(k)
+        }
+
+        pub(crate) fn scale_by_cofactor(&self) -> G2 {
             // G2 cofactor = 2p - n = 2q - r
             // 0x30644e72e131a029b85045b68181585e06ceecda572a2489345f2299c0f9fa8d
             let cofactor = BitIterator::new([
@@ -1330,6 +1660,19 @@ pub mod g2 {
             self.mul_bits(cofactor)
         }
 
+        /// Checks that a point on E' is in G2, using that it is so if and only
+        /// if psi(P) = [6u^2]P, as 6u^2 = q - r.
+        fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+            if self.is_zero() {
+                return true;
+            }
+
+            // 6u^2 = 0x6f4d8248eeb859fbf83e9682e87cfd46
+            let six_u_squared = BitIterator::new([0xf83e9682e87cfd46, 0x6f4d8248eeb859fb]);
+
+            self.into_projective().psi() == self.mul_bits(six_u_squared)
+        }
+
         fn get_generator() -> Self {
             G2Affine {
                 x: Fq2 {
@@ -1358,6 +1701,48 @@ pub mod g2 {
     }
 
     impl G2 {
+        /// The untwist-Frobenius-twist endomorphism, which acts on G2 as
+        /// multiplication by q.
+        fn psi(&self) -> G2 {
+            let mut res = *self;
+
+            res.x.c1.negate();
+            res.x.mul_assign(&super::super::fq::FROBENIUS_COEFF_FQ6_C1[1]);
+
+            res.y.c1.negate();
+            res.y.mul_assign(&super::super::fq::XI_TO_Q_MINUS_1_OVER_2);
+
+            res.z.c1.negate();
+
+            res
+        }
+
+        /// Multiplies by `k` with the GLS method, which is faster than
+        /// `mul_assign`. The result is only correct for points in G2, on which
+        /// psi acts as multiplication by 6u^2, so points of the twist that were
+        /// not checked to be in G2 must use `mul_assign`.
+        pub fn mul_gls<S: Into<FrRepr>>(&self, k: S) -> G2 {
+            glv_mul(self, k.into(), &GLS_PARAMETERS, G2::psi)
+        }
+
+        /// Maps a point of E' into G2 with the method of Fuentes-Castaneda,
+        /// Knapp and Rodriguez-Henriquez ("Faster hashing to G2"),
+        /// h(P) = [u]P + psi([3u]P) + psi^2([u]P) + psi^3(P), which is a
+        /// multiple of the cofactor 2q - r coprime to r.
+        fn mul_by_effective_cofactor(&self) -> G2 {
+            let t0 = self.mul_bits(BitIterator::new([super::super::BN_U]));
+
+            let mut t1 = t0;
+            t1.double();
+            t1.add_assign(&t0);
+
+            let mut res = self.psi().psi().psi();
+            res.add_assign(&t0);
+            res.add_assign(&t1.psi());
+            res.add_assign(&t0.psi().psi());
+            res
+        }
+
         fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
             let num_bits = scalar.num_bits() as usize;
 
@@ -1387,9 +1772,31 @@ pub mod g2 {
         }
     }
 
-    #[derive(Clone, Debug)]
+    #[derive(Clone, Debug, PartialEq, Eq)]
     pub struct G2Prepared {
-        pub(crate) coeffs: Vec<(Fq2, Fq2, Fq2)>,
+        pub(crate) p: G2Affine,
+        pub(crate) lines: G2PreparedLines,
+        pub(crate) infinity: bool,
+    }
+
+    /// The line coefficients of a `G2Prepared`.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub(crate) enum G2PreparedLines {
+        /// The coefficients of `y`, `x` and the constant term of each line,
+        /// as computed in projective coordinates.
+        Projective(Vec<(Fq2, Fq2, Fq2)>),
+        /// The lines of a `G2PreparedAffine`.
+        Affine(Vec<(Fq2, Fq2)>),
+    }
+
+    /// A prepared element of G2 whose lines are normalized to `y + a * x + b`
+    /// and stored as `(a, b)`, which takes two thirds of the memory of
+    /// `G2Prepared`. It can be paired directly or converted into a
+    /// `G2Prepared` for `Engine::miller_loop`.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct G2PreparedAffine {
+        pub(crate) p: G2Affine,
+        pub(crate) coeffs: Vec<(Fq2, Fq2)>,
         pub(crate) infinity: bool,
     }
 
@@ -1512,12 +1919,170 @@ pub mod g2 {
         panic!();
     }
 
+    #[test]
+    fn test_gls_decomposition() {
+        use crate::glv::glv_decompose;
+        use rand::{SeedableRng, XorShiftRng};
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let mut minus_one = Fr::one();
+        minus_one.negate();
+
+        let mut scalars = vec![Fr::zero(), Fr::one(), minus_one];
+        scalars.extend((0..1000).map(|_| Fr::rand(&mut rng)));
+
+        for k in scalars {
+            for (_, k_i) in glv_decompose::<Fr>(k.into_repr(), &GLS_PARAMETERS) {
+                assert!(k_i.num_bits() <= 66);
+            }
+        }
+    }
+
+    #[test]
+    fn test_g2_mul_gls() {
+        crate::tests::curve::endomorphism_mul_tests::<G2, _>(|p, k| p.mul_gls(k));
+        crate::tests::curve::endomorphism_mul_tests::<G2, _>(|p, k| p.into_affine().mul_gls(k));
+    }
+
     #[test]
     fn g2_curve_tests() {
         crate::tests::curve::curve_tests::<G2>();
         crate::tests::curve::random_transformation_tests::<G2>();
     }
 
+    #[test]
+    fn g2_ct_tests() {
+        crate::tests::curve::ct_tests::<G2>();
+    }
+
+    #[test]
+    fn g2_test_is_valid() {
+        // Reject point on E' that is not in G2
+        {
+            let p = G2Affine {
+                x: Fq2::one(),
+                y: Fq2 {
+                    c0: Fq::from_hex("2869111d5381f072f8e2728fdb825a51aadd70e52c9830e9ab4b871c0531f1bb").unwrap(),
+                    c1: Fq::from_hex("0d1271953ed9ea0836846e70a1934187998c7f790cb4d7511b7f8da82de048a4").unwrap(),
+                },
+                infinity: false,
+            };
+            assert!(p.is_on_curve());
+            assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+
+            assert!(matches!(
+                p.into_uncompressed().into_affine(),
+                Err(GroupDecodingError::NotInSubgroup)
+            ));
+            assert!(matches!(
+                p.into_compressed().into_affine(),
+                Err(GroupDecodingError::NotInSubgroup)
+            ));
+            assert!(p.into_uncompressed().into_affine_unchecked().is_ok());
+            assert!(p.into_compressed().into_affine_unchecked().is_ok());
+
+            assert!(matches!(
+                G2Affine::from_xy_checked(p.x(), p.y()),
+                Err(GroupDecodingError::NotInSubgroup)
+            ));
+            assert_eq!(G2Affine::from_xy_unchecked(p.x(), p.y()), p);
+        }
+
+        // Reject point of small order 10069, which divides the cofactor
+        {
+            let p = G2Affine {
+                x: Fq2 {
+                    c0: Fq::from_hex("25d0b2ba5c369c1dbbfed41ccb191fe4df508cf88faf370494b4867a332815ec").unwrap(),
+                    c1: Fq::from_hex("0f0a87c117963381ad55ab9b17e3c4f9533ef81d16b752be905d1a444b982519").unwrap(),
+                },
+                y: Fq2 {
+                    c0: Fq::from_hex("2e4585f85183195d71c90a2d1d38655cb0ff30b352fdf58d6a36d8fbb3ee84a1").unwrap(),
+                    c1: Fq::from_hex("01961c59a0d89a4d27fe2b87137614a142c3ad7ada8b3fb5580462e2a5a99847").unwrap(),
+                },
+                infinity: false,
+            };
+            assert!(p.is_on_curve());
+            assert!(p.mul(FrRepr::from(10069)).is_zero());
+            assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+
+            assert!(matches!(
+                p.into_uncompressed().into_affine(),
+                Err(GroupDecodingError::NotInSubgroup)
+            ));
+            assert!(matches!(
+                p.into_compressed().into_affine(),
+                Err(GroupDecodingError::NotInSubgroup)
+            ));
+
+            #[cfg(feature = "serde")]
+            crate::tests::serialization::serde_rejects_point(p);
+        }
+
+        // The fast check agrees with multiplication by r
+        {
+            let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+            let mut checked = 0;
+            while checked < 10 {
+                let x: Fq2 = rng.gen();
+                if let Some(p) = G2Affine::get_point_from_x(x, rng.gen()) {
+                    assert_eq!(
+                        p.is_in_correct_subgroup_assuming_on_curve(),
+                        p.mul(Fr::char()).is_zero()
+                    );
+                    assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+
+                    let p = p.scale_by_cofactor().into_affine();
+                    assert!(p.is_in_correct_subgroup_assuming_on_curve());
+                    assert_eq!(p.into_compressed().into_affine().unwrap(), p);
+                    assert_eq!(p.into_uncompressed().into_affine().unwrap(), p);
+
+                    checked += 1;
+                }
+            }
+
+            assert!(G2Affine::zero().is_in_correct_subgroup_assuming_on_curve());
+            assert!(G2Affine::one().is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+
+    #[test]
+    fn test_g2_clear_cofactor() {
+        use rand::{SeedableRng, XorShiftRng};
+
+        let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+        let mut i = 0;
+        while i < 10 {
+            let x: Fq2 = rng.gen();
+            if let Some(p) = G2Affine::get_point_from_x(x, rng.gen()) {
+                assert!(p.is_on_curve());
+                assert!(!p.is_in_subgroup());
+
+                let q = p.clear_cofactor().into_affine();
+                assert!(!q.is_zero());
+                assert!(q.is_in_subgroup());
+                assert!(q.mul_bits(BitIterator::new(Fr::char())).is_zero());
+
+                i += 1;
+            }
+        }
+
+        // On G2, psi acts as multiplication by q, so the map is
+        // multiplication by u + 3uq + uq^2 + q^3 mod r
+        let h = FrRepr([
+            0xb5eb5854608fc9cd,
+            0x4998e3b69c48bc97,
+            0xb85045b68181585c,
+            0x30644e72e131a029,
+        ]);
+        for _ in 0..10 {
+            let p = G2::rand(&mut rng);
+            assert_eq!(p.clear_cofactor(), p.into_affine().mul_bits(BitIterator::new(h)));
+        }
+    }
+
     #[test]
 
     fn test_b_coeff() {
@@ -1693,6 +2258,10 @@ pub mod g2 {
         }
     }
 
+    #[test]
+    fn test_g2_xy_constructors() {
+        crate::tests::curve::xy_constructor_tests::<G2>();
+    }
 }
 
 pub use self::g1::*;
@@ -6,6 +6,11 @@ use ff::{Field, PrimeField, PrimeFieldRepr};
 #[PrimeFieldGenerator = "2"]
 pub struct Fq(FqRepr);
 
+ct_prime_field_impl!(Fq, FqRepr);
+
+#[cfg(feature = "serde")]
+serde_impl!(Fq, crate::serialization::serialize_field, crate::serialization::deserialize_field);
+
 // B coefficient of BN256 curve, B = 3
 // In Montgommery form with R = 2^256
 pub const B_COEFF: Fq = Fq(FqRepr([
@@ -432,15 +437,209 @@ pub const FROBENIUS_COEFF_FQ12_C1: [Fq2; 12] = [
 
 // -((2**256) mod q) mod q
 pub const NEGATIVE_ONE: Fq = Fq(FqRepr([
-    0x974bc177a0000006,
-    0xf13771b2da58a367,
-    0x51e1a2470908122e,
+    0x68c3488912edefaa,
+    0x8d087f6872aabf4f,
+    0x51e1a24709081231,
     0x2259d6b14729c0fa,
 ]));
 
+// Constants of the Shallue-van de Woestijne map to E, RFC 9380, section 6.6.1,
+// with Z = 1 as found by find_z_svdw (RFC 9380, appendix H.1)
+pub const SVDW_Z: Fq = Fq(FqRepr([
+    0xd35d438dc58f0d9d,
+    0x0a78eb28f5c70b3d,
+    0x666ea36f7879462c,
+    0x0e0a77c19a07df2f,
+]));
+
+// c1 = g(Z), c2 = -Z / 2, c3 = sqrt(-g(Z) * 3 * Z^2) with sgn0(c3) = 0,
+// c4 = -4 * g(Z) / (3 * Z^2)
+pub const SVDW_C: [Fq; 4] = [
+    Fq(FqRepr([
+        0x115482203dbf392d,
+        0x926242126eaa626a,
+        0xe16a48076063c052,
+        0x07c5909386eddc93,
+    ])),
+    Fq(FqRepr([
+        0xb461a4448976f7d5,
+        0xc6843fb439555fa7,
+        0x28f0d12384840918,
+        0x112ceb58a394e07d,
+    ])),
+    Fq(FqRepr([
+        0x7c8487078735ab72,
+        0x51da7e0048bfb8d4,
+        0x945cfd183cbd7bf4,
+        0x0b70b1ec48ae62c6,
+    ])),
+    Fq(FqRepr([
+        0xa79a2bdca0800831,
+        0x19fd7617e49815a1,
+        0xbb8d0c885550c7b1,
+        0x05c4aeb6ec7e0f48,
+    ])),
+];
+
+// Constants of the Shallue-van de Woestijne map to E', with Z = 1
+pub const SVDW_Z2: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ])),
+    c1: Fq(FqRepr([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+};
+
+pub const SVDW_C2: [Fq2; 4] = [
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xd335f05a64ca12fe,
+            0x75029bbec388940d,
+            0xd4d64ba9406d402e,
+            0x02baef80fc5ae772,
+        ])),
+        c1: Fq(FqRepr([
+            0x38e7ecccd1dcff67,
+            0x65f0b37d93ce0d3e,
+            0xd749d0dd22ac00aa,
+            0x0141b9ce4a688d4d,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xb461a4448976f7d5,
+            0xc6843fb439555fa7,
+            0x28f0d12384840918,
+            0x112ceb58a394e07d,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xaaad0cab9a24277f,
+            0xf2209f5b7e5b757a,
+            0xc3a46b7e850013a7,
+            0x1f9e7f3768c5c9af,
+        ])),
+        c1: Fq(FqRepr([
+            0x412278c8de85d863,
+            0xfe3e4c7f559d375a,
+            0x5e44b9da0a96ad23,
+            0x297d818d387725c8,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x63cdc796b49b3a32,
+            0x73a8220d40eb16f6,
+            0xb46d1eed55c49000,
+            0x1c9ef4f5f0528b82,
+        ])),
+        c1: Fq(FqRepr([
+            0x9aeb505b1600fe13,
+            0x64eb25e9f8b4638f,
+            0x43edd9e4fdf1577a,
+            0x2eb756b528a63917,
+        ])),
+    },
+];
+
+// A primitive cube root of unity, such that the endomorphism
+// sigma(x, y) = (BETA * x, y) of E acts on G1 as multiplication by
+// 0xb3c4d79d41a917585bfc41088d8daaa78b17ea66b99c90dd.
+// 0x59e26bcea0d48bacd4f263f1acdb5c4f5763473177fffffe
+pub const BETA: Fq = Fq(FqRepr([
+    0x71930c11d782e155,
+    0xa6bb947cffbe3323,
+    0xaa303344d4741444,
+    0x2c3b3f0d26594943,
+]));
+
 #[cfg(test)]
 use rand::{Rand, SeedableRng, XorShiftRng};
 
+#[test]
+fn test_neg_one() {
+    let mut o = Fq::one();
+    o.negate();
+
+    assert_eq!(NEGATIVE_ONE, o);
+}
+
+#[test]
+fn test_beta() {
+    assert!(BETA != Fq::one());
+    assert_eq!(BETA.pow([3]), Fq::one());
+    assert_eq!(
+        BETA,
+        Fq::from_str("2203960485148121921418603742825762020974279258880205651966").unwrap()
+    );
+}
+
+#[test]
+fn test_svdw_coeffs() {
+    use crate::SqrtField;
+
+    fn check<F: SqrtField>(z: &F, b: &F, c: &[F; 4]) {
+        // g(Z) = Z^3 + B
+        let mut gz = *z;
+        gz.square();
+        gz.mul_assign(z);
+        gz.add_assign(b);
+        assert_eq!(c[0], gz);
+
+        // c2 = -Z / 2
+        let mut tmp = c[1];
+        tmp.double();
+        tmp.add_assign(z);
+        assert!(tmp.is_zero());
+
+        // 3 * Z^2
+        let mut three_z2 = *z;
+        three_z2.square();
+        let mut tmp = three_z2;
+        tmp.double();
+        three_z2.add_assign(&tmp);
+
+        // c3^2 = -g(Z) * 3 * Z^2
+        let mut lhs = c[2];
+        lhs.square();
+        let mut rhs = gz;
+        rhs.mul_assign(&three_z2);
+        rhs.negate();
+        assert_eq!(lhs, rhs);
+
+        // c4 * 3 * Z^2 = -4 * g(Z)
+        let mut lhs = c[3];
+        lhs.mul_assign(&three_z2);
+        let mut rhs = gz;
+        rhs.double();
+        rhs.double();
+        rhs.negate();
+        assert_eq!(lhs, rhs);
+    }
+
+    assert_eq!(SVDW_Z, Fq::one());
+    assert_eq!(SVDW_Z2, Fq2::one());
+    assert!(SVDW_C[2].into_repr().is_even());
+    assert!(SVDW_C2[2].c0.into_repr().is_even());
+
+    check(&SVDW_Z, &B_COEFF, &SVDW_C);
+    check(&SVDW_Z2, &B_COEFF_FQ2, &SVDW_C2);
+}
+
 #[test]
 fn test_fq_repr_from() {
     assert_eq!(FqRepr::from(100), FqRepr([100, 0, 0, 0]));
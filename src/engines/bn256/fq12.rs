@@ -1,11 +1,16 @@
-use super::fq::FROBENIUS_COEFF_FQ12_C1;
+use super::fq::{FqRepr, FROBENIUS_COEFF_FQ12_C1};
 use super::fq2::Fq2;
 use super::fq6::Fq6;
-use ff::Field;
+use crate::gt::{read_fq, write_fq};
+use crate::wnaf::naf_form;
+use crate::{GroupDecodingError, SexticTargetField, TargetField, T6};
+use ff::{Field, PrimeFieldDecodingError};
 use rand::{Rand, Rng};
+use std::mem;
 
 /// An element of Fq12, represented by c0 + c1 * w.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fq12 {
     pub c0: Fq6,
     pub c1: Fq6,
@@ -34,6 +39,91 @@ impl Fq12 {
         self.c1.negate();
     }
 
+    /// Squares this element, which must be in the cyclotomic subgroup, with
+    /// the formulas of Granger and Scott.
+    pub fn cyclotomic_square(&mut self) {
+        let z0 = self.c0.c0;
+        let z4 = self.c0.c1;
+        let z3 = self.c0.c2;
+        let z2 = self.c1.c0;
+        let z1 = self.c1.c1;
+        let z5 = self.c1.c2;
+
+        let (t0, t1) = fq4_square(&z0, &z1);
+        self.c0.c0 = triple_minus_double(&t0, &z0);
+        self.c1.c1 = triple_plus_double(&t1, &z1);
+
+        let (t0, t1) = fq4_square(&z2, &z3);
+        let (t2, mut t3) = fq4_square(&z4, &z5);
+        self.c0.c1 = triple_minus_double(&t0, &z4);
+        self.c1.c2 = triple_plus_double(&t1, &z5);
+
+        t3.mul_by_nonresidue();
+        self.c1.c0 = triple_plus_double(&t3, &z2);
+        self.c0.c2 = triple_minus_double(&t2, &z3);
+    }
+
+    /// Raises this element, which must be in the cyclotomic subgroup, to the
+    /// power `exp` using its non-adjacent form and cyclotomic squarings.
+    pub fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut inv = *self;
+        inv.conjugate();
+
+        let mut res = Fq12::one();
+        let mut found_nonzero = false;
+        for &digit in naf_form(exp.as_ref()).iter().rev() {
+            if found_nonzero {
+                res.cyclotomic_square();
+            }
+
+            if digit != 0 {
+                found_nonzero = true;
+                res.mul_assign(if digit > 0 { self } else { &inv });
+            }
+        }
+
+        res
+    }
+
+    /// Raises this element, which must be in the cyclotomic subgroup, to the
+    /// power `exp` by squaring in Karabina's compressed form, decompressing
+    /// the powers needed for the non-zero digits of `exp` all at once. Falls
+    /// back to `cyclotomic_exp` if a power can't be decompressed.
+    pub fn compressed_cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let naf = naf_form(exp.as_ref());
+
+        let mut powers = vec![];
+        let mut c = match CompressedFq12::compress(self) {
+            Some(c) => c,
+            None => return self.cyclotomic_exp(exp),
+        };
+        for (i, &digit) in naf.iter().enumerate() {
+            if i > 0 {
+                c.square();
+            }
+
+            if digit != 0 {
+                powers.push((c, digit < 0));
+            }
+        }
+
+        let compressed: Vec<_> = powers.iter().map(|(c, _)| *c).collect();
+        let decompressed = match CompressedFq12::batch_decompress(&compressed) {
+            Some(decompressed) => decompressed,
+            None => return self.cyclotomic_exp(exp),
+        };
+
+        let mut res = Fq12::one();
+        for (mut f, (_, negative)) in decompressed.into_iter().zip(powers.iter()) {
+            if *negative {
+                f.conjugate();
+            }
+            res.mul_assign(&f);
+        }
+
+        res
+    }
+
     pub fn mul_by_014(&mut self, c0: &Fq2, c1: &Fq2, c4: &Fq2) {
         let mut aa = self.c0;
         aa.mul_by_01(c0, c1);
@@ -167,6 +257,303 @@ impl Field for Fq12 {
     }
 }
 
+/// Squares `a + b * s` in `Fq4 = Fq2[s] / (s^2 - xi)`.
+fn fq4_square(a: &Fq2, b: &Fq2) -> (Fq2, Fq2) {
+    let mut a2 = *a;
+    a2.square();
+    let mut b2 = *b;
+    b2.square();
+
+    let mut c0 = b2;
+    c0.mul_by_nonresidue();
+    c0.add_assign(&a2);
+
+    let mut c1 = *a;
+    c1.add_assign(b);
+    c1.square();
+    c1.sub_assign(&a2);
+    c1.sub_assign(&b2);
+
+    (c0, c1)
+}
+
+/// Returns `3 * t - 2 * z`.
+fn triple_minus_double(t: &Fq2, z: &Fq2) -> Fq2 {
+    let mut res = *t;
+    res.sub_assign(z);
+    res.double();
+    res.add_assign(t);
+    res
+}
+
+/// Returns `3 * t + 2 * z`.
+fn triple_plus_double(t: &Fq2, z: &Fq2) -> Fq2 {
+    let mut res = *t;
+    res.add_assign(z);
+    res.double();
+    res.add_assign(t);
+    res
+}
+
+/// An element `g0 + g1 * v + g2 * v^2 + (g3 + g4 * v + g5 * v^2) * w` of the
+/// cyclotomic subgroup of `Fq12` in the compressed form of Karabina, which
+/// drops `g0` and `g4`. Squaring is cheaper in this form, and decompressions
+/// can be batched to share a single inversion.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CompressedFq12 {
+    g1: Fq2,
+    g2: Fq2,
+    g3: Fq2,
+    g5: Fq2,
+}
+
+impl CompressedFq12 {
+    /// Compresses `f`, which must be in the cyclotomic subgroup. Returns
+    /// `None` if `g2 = g3 = 0` and `f` isn't one, as `g4` can't be recovered
+    /// then; `-1`, for instance, would otherwise compress like one.
+    pub fn compress(f: &Fq12) -> Option<Self> {
+        let c = CompressedFq12 {
+            g1: f.c0.c1,
+            g2: f.c0.c2,
+            g3: f.c1.c0,
+            g5: f.c1.c2,
+        };
+
+        if c.g2.is_zero() && c.g3.is_zero() && *f != Fq12::one() {
+            return None;
+        }
+
+        Some(c)
+    }
+
+    /// Squares this element.
+    pub fn square(&mut self) {
+        let mut g1g5 = self.g1;
+        g1g5.mul_assign(&self.g5);
+        g1g5.double();
+        g1g5.mul_by_nonresidue();
+
+        let mut g2g3 = self.g2;
+        g2g3.mul_assign(&self.g3);
+        g2g3.double();
+
+        let mut g1_2 = self.g1;
+        g1_2.square();
+        let mut g2_2 = self.g2;
+        g2_2.square();
+        let mut g3_2 = self.g3;
+        g3_2.square();
+        let mut g5_2 = self.g5;
+        g5_2.square();
+
+        // g1' = 3 * (g3^2 + xi * g2^2) - 2 * g1
+        g2_2.mul_by_nonresidue();
+        g3_2.add_assign(&g2_2);
+        let g1 = triple_minus_double(&g3_2, &self.g1);
+
+        // g2' = 3 * (g1^2 + xi * g5^2) - 2 * g2
+        g5_2.mul_by_nonresidue();
+        g1_2.add_assign(&g5_2);
+        let g2 = triple_minus_double(&g1_2, &self.g2);
+
+        // g3' = 3 * 2 * xi * g1 * g5 + 2 * g3
+        let g3 = triple_plus_double(&g1g5, &self.g3);
+
+        // g5' = 3 * 2 * g2 * g3 + 2 * g5
+        let g5 = triple_plus_double(&g2g3, &self.g5);
+
+        *self = CompressedFq12 { g1, g2, g3, g5 };
+    }
+
+    /// Returns the element of `Fq12` this represents, or `None` if it has
+    /// `g2 = g3 = 0` without being one.
+    pub fn decompress(&self) -> Option<Fq12> {
+        Self::batch_decompress(&[*self]).map(|mut v| v.pop().unwrap())
+    }
+
+    /// Decompresses all of `elements` with a single inversion. Returns `None`
+    /// if any of them has `g2 = g3 = 0` without being one.
+    pub fn batch_decompress(elements: &[Self]) -> Option<Vec<Fq12>> {
+        // With g2 = g3 = 0, g4 is undetermined unless g1 = g5 = 0 too: the
+        // only such element of the cyclotomic subgroup is one, since it meets
+        // Fq4 = Fq2(v * w) trivially.
+        if elements.iter().any(|e| {
+            e.g2.is_zero() && e.g3.is_zero() && !(e.g1.is_zero() && e.g5.is_zero())
+        }) {
+            return None;
+        }
+
+        // g4 = (xi * g5^2 + 3 * g1^2 - 2 * g2) / (4 * g3) if g3 is non-zero,
+        // and 2 * g1 * g5 / g2 otherwise.
+        let fractions: Vec<(Fq2, Fq2)> = elements
+            .iter()
+            .map(|e| {
+                if e.g3.is_zero() {
+                    let mut num = e.g1;
+                    num.mul_assign(&e.g5);
+                    num.double();
+
+                    (num, e.g2)
+                } else {
+                    let mut g1_2 = e.g1;
+                    g1_2.square();
+                    let mut num = e.g5;
+                    num.square();
+                    num.mul_by_nonresidue();
+                    num.add_assign(&triple_minus_double(&g1_2, &e.g2));
+
+                    let mut den = e.g3;
+                    den.double();
+                    den.double();
+
+                    (num, den)
+                }
+            })
+            .collect();
+
+        // Montgomery's trick, skipping the zero denominators of one.
+        let mut prefix = Vec::with_capacity(fractions.len());
+        let mut acc = Fq2::one();
+        for (_, den) in fractions.iter() {
+            prefix.push(acc);
+            if !den.is_zero() {
+                acc.mul_assign(den);
+            }
+        }
+
+        let mut acc_inv = acc.inverse().expect("denominators are non-zero");
+        let mut g4s = vec![None; fractions.len()];
+        for (i, (num, den)) in fractions.iter().enumerate().rev() {
+            if den.is_zero() {
+                continue;
+            }
+
+            let mut g4 = acc_inv;
+            g4.mul_assign(&prefix[i]);
+            g4.mul_assign(num);
+            g4s[i] = Some(g4);
+            acc_inv.mul_assign(den);
+        }
+
+        let res = elements
+            .iter()
+            .zip(g4s)
+            .map(|(e, g4)| match g4 {
+                // g0 = xi * (2 * g4^2 + g3 * g5 - 3 * g1 * g2) + 1
+                Some(g4) => {
+                    let mut g0 = g4;
+                    g0.square();
+                    g0.double();
+                    let mut t = e.g3;
+                    t.mul_assign(&e.g5);
+                    g0.add_assign(&t);
+                    let mut t = e.g1;
+                    t.mul_assign(&e.g2);
+                    g0.sub_assign(&t);
+                    t.double();
+                    g0.sub_assign(&t);
+                    g0.mul_by_nonresidue();
+                    g0.add_assign(&Fq2::one());
+
+                    Fq12 {
+                        c0: Fq6 { c0: g0, c1: e.g1, c2: e.g2 },
+                        c1: Fq6 { c0: e.g3, c1: g4, c2: e.g5 },
+                    }
+                }
+                // g1 = g2 = g3 = g5 = 0 only for one
+                None => Fq12::one(),
+            })
+            .collect();
+
+        Some(res)
+    }
+}
+
+const FQ_SIZE: usize = mem::size_of::<FqRepr>();
+
+impl TargetField for Fq12 {
+    type Subfield = Fq6;
+
+    const SUBFIELD_SIZE: usize = 3 * Self::FQE_SIZE;
+
+    const COMPRESSED_SIZE: usize = T6::<Fq12>::SIZE;
+
+    fn from_subfield(c0: Fq6, c1: Fq6) -> Self {
+        Fq12 { c0, c1 }
+    }
+
+    fn into_subfield(self) -> (Fq6, Fq6) {
+        (self.c0, self.c1)
+    }
+
+    fn mul_subfield_by_nonresidue(c: &mut Fq6) {
+        c.mul_by_nonresidue();
+    }
+
+    fn write_subfield(c: &Fq6, bytes: &mut [u8]) {
+        let (c2, rest) = bytes.split_at_mut(Self::FQE_SIZE);
+        let (c1, c0) = rest.split_at_mut(Self::FQE_SIZE);
+
+        Self::write_fqe(&c.c2, c2);
+        Self::write_fqe(&c.c1, c1);
+        Self::write_fqe(&c.c0, c0);
+    }
+
+    fn read_subfield(bytes: &[u8]) -> Result<Fq6, PrimeFieldDecodingError> {
+        let c2 = Self::read_fqe(&bytes[..Self::FQE_SIZE])?;
+        let c1 = Self::read_fqe(&bytes[Self::FQE_SIZE..2 * Self::FQE_SIZE])?;
+        let c0 = Self::read_fqe(&bytes[2 * Self::FQE_SIZE..])?;
+
+        Ok(Fq6 { c0, c1, c2 })
+    }
+
+    fn conjugate(&mut self) {
+        Fq12::conjugate(self);
+    }
+
+    fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        Fq12::cyclotomic_exp(self, exp)
+    }
+
+    fn write_compressed(&self, bytes: &mut [u8]) {
+        T6::compress_unchecked(self).write(bytes);
+    }
+
+    fn read_compressed(bytes: &[u8]) -> Result<Self, GroupDecodingError> {
+        Ok(T6::<Fq12>::read(bytes)?.decompress())
+    }
+}
+
+impl SexticTargetField for Fq12 {
+    type Fqe = Fq2;
+
+    const FQE_SIZE: usize = 2 * FQ_SIZE;
+
+    fn from_fqe(c0: Fq2, c1: Fq2, c2: Fq2) -> Fq6 {
+        Fq6 { c0, c1, c2 }
+    }
+
+    fn into_fqe(c: &Fq6) -> (Fq2, Fq2, Fq2) {
+        (c.c0, c.c1, c.c2)
+    }
+
+    fn mul_fqe_by_nonresidue(c: &mut Fq2) {
+        c.mul_by_nonresidue();
+    }
+
+    fn write_fqe(c: &Fq2, bytes: &mut [u8]) {
+        write_fq(&c.c1, &mut bytes[..FQ_SIZE]);
+        write_fq(&c.c0, &mut bytes[FQ_SIZE..]);
+    }
+
+    fn read_fqe(bytes: &[u8]) -> Result<Fq2, PrimeFieldDecodingError> {
+        let c1 = read_fq(&bytes[..FQ_SIZE])?;
+        let c0 = read_fq(&bytes[FQ_SIZE..])?;
+
+        Ok(Fq2 { c0, c1 })
+    }
+}
+
 #[cfg(test)]
 use rand::{SeedableRng, XorShiftRng};
 
@@ -212,6 +599,89 @@ fn test_squaring() {
     }
 }
 
+/// Returns a random element of the cyclotomic subgroup, `u^(q^2 + 1)` for
+/// `u = conj(f) / f`.
+#[cfg(test)]
+fn random_cyclotomic<R: Rng>(rng: &mut R) -> Fq12 {
+    let f = Fq12::rand(rng);
+    let mut u = f;
+    u.conjugate();
+    u.mul_assign(&f.inverse().unwrap());
+
+    let mut res = u;
+    res.frobenius_map(2);
+    res.mul_assign(&u);
+    res
+}
+
+#[test]
+fn test_fq12_cyclotomic_square() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    let mut one = Fq12::one();
+    one.cyclotomic_square();
+    assert_eq!(one, Fq12::one());
+    assert_eq!(CompressedFq12::compress(&one).unwrap().decompress(), Some(one));
+
+    // -1 has the same g1, g2, g3 and g5 as one.
+    let mut minus_one = Fq12::one();
+    minus_one.negate();
+    assert_eq!(CompressedFq12::compress(&minus_one), None);
+    for exp in [vec![1], vec![2], vec![3], vec![u64::max_value()]].iter() {
+        assert_eq!(
+            minus_one.compressed_cyclotomic_exp(exp),
+            minus_one.cyclotomic_exp(exp)
+        );
+    }
+
+    for _ in 0..100 {
+        let a = random_cyclotomic(&mut rng);
+
+        let mut expected = a;
+        let mut b = a;
+        let mut c = CompressedFq12::compress(&a).unwrap();
+        let mut compressed = vec![c];
+        let mut squares = vec![a];
+        for _ in 0..5 {
+            expected.square();
+            b.cyclotomic_square();
+            c.square();
+            assert_eq!(b, expected);
+            assert_eq!(c.decompress(), Some(expected));
+
+            compressed.push(c);
+            squares.push(expected);
+        }
+
+        compressed.push(CompressedFq12::compress(&Fq12::one()).unwrap());
+        squares.push(Fq12::one());
+        assert_eq!(CompressedFq12::batch_decompress(&compressed), Some(squares));
+    }
+}
+
+#[test]
+fn test_fq12_cyclotomic_exp() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    for _ in 0..100 {
+        let a = random_cyclotomic(&mut rng);
+
+        for exp in [
+            vec![0],
+            vec![1],
+            vec![u64::max_value()],
+            vec![u64::max_value(), u64::max_value()],
+            vec![rng.gen()],
+            vec![rng.gen(), rng.gen(), rng.gen(), rng.gen()],
+        ]
+        .iter()
+        {
+            assert_eq!(a.cyclotomic_exp(exp), a.pow(exp));
+            assert_eq!(a.compressed_cyclotomic_exp(exp), a.pow(exp));
+        }
+    }
+}
+
 #[test]
 fn fq12_field_tests() {
     use ff::PrimeField;
@@ -219,3 +689,9 @@ fn fq12_field_tests() {
     crate::tests::field::random_field_tests::<Fq12>();
     crate::tests::field::random_frobenius_tests::<Fq12, _>(super::fq::Fq::char(), 13);
 }
+
+#[test]
+fn fq12_torus_tests() {
+    crate::tests::torus::t2_tests::<Fq12>();
+    crate::tests::torus::t6_tests::<Fq12>();
+}
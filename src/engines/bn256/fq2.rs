@@ -1,11 +1,13 @@
 use super::fq::{FROBENIUS_COEFF_FQ2_C1, Fq, NEGATIVE_ONE};
-use ff::{Field, SqrtField};
+use crate::ct::{fermat_inverse, Choice, ConditionallySelectable, ConstantTimeEq, CtField, CtOption};
+use ff::{Field, PrimeField, SqrtField};
 use rand::{Rand, Rng};
 
 use std::cmp::Ordering;
 
 /// An element of Fq2, represented by c0 + c1 * u.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fq2 {
     pub c0: Fq,
     pub c1: Fq,
@@ -203,6 +205,28 @@ impl Field for Fq2 {
     }
 }
 
+impl ConditionallySelectable for Fq2 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fq2 {
+            c0: Fq::conditional_select(&a.c0, &b.c0, choice),
+            c1: Fq::conditional_select(&a.c1, &b.c1, choice),
+        }
+    }
+}
+
+impl ConstantTimeEq for Fq2 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+impl CtField for Fq2 {
+    fn ct_inverse(&self) -> CtOption<Self> {
+        let inverse = fermat_inverse(self, Fq::char().as_ref(), 2);
+        CtOption::new(inverse, !self.ct_eq(&Self::zero()))
+    }
+}
+
 impl SqrtField for Fq2 {
     fn legendre(&self) -> ::ff::LegendreSymbol {
         self.norm().legendre()
@@ -928,6 +952,7 @@ fn test_fq2_legendre() {
     assert_eq!(QuadraticResidue, m1.legendre());
     m1.mul_by_nonresidue();
     assert_eq!(QuadraticNonResidue, m1.legendre());
+    assert!(m1.sqrt().is_none());
 }
 
 #[cfg(test)]
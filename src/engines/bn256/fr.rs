@@ -5,6 +5,11 @@ use ff::{Field, PrimeField, PrimeFieldRepr};
 #[PrimeFieldGenerator = "7"]
 pub struct Fr(FrRepr);
 
+ct_prime_field_impl!(Fr, FrRepr);
+
+#[cfg(feature = "serde")]
+serde_impl!(Fr, crate::serialization::serialize_field, crate::serialization::deserialize_field);
+
 #[test]
 fn test_to_hex() {
     assert_eq!(Fr::one().to_hex(), "0000000000000000000000000000000000000000000000000000000000000001");
@@ -0,0 +1,255 @@
+use super::fq::{B_COEFF, B_COEFF_FQ2, SVDW_C, SVDW_C2, SVDW_Z, SVDW_Z2};
+use super::{Fq, Fq2, G1, G1Affine, G2, G2Affine};
+use crate::hash_to_curve::{from_be_bytes_mod_order, hash_to_field, map_to_curve_svdw, Sgn0};
+use crate::{CurveAffine, CurveProjective, HashToCurve};
+
+use ff::{Field, PrimeField, PrimeFieldRepr, SqrtField};
+use sha2::{Digest, Sha256};
+
+impl Sgn0 for Fq {
+    fn sgn0(&self) -> bool {
+        self.into_repr().is_odd()
+    }
+}
+
+impl Sgn0 for Fq2 {
+    fn sgn0(&self) -> bool {
+        let sign_0 = self.c0.sgn0();
+        let zero_0 = self.c0.is_zero();
+        let sign_1 = self.c1.sgn0();
+
+        sign_0 || (zero_0 && sign_1)
+    }
+}
+
+/// Maps a field element to E with the Shallue-van de Woestijne map. G1 has
+/// cofactor one, so the result is already in G1.
+fn map_to_curve_g1(u: &Fq) -> G1 {
+    let (x, y) = map_to_curve_svdw(u, &Fq::zero(), &B_COEFF, &SVDW_Z, &SVDW_C);
+
+    G1Affine {
+        x,
+        y,
+        infinity: false,
+    }
+    .into_projective()
+}
+
+/// Maps a field element to E' with the Shallue-van de Woestijne map. The
+/// result is not yet in G2.
+fn map_to_curve_g2(u: &Fq2) -> G2 {
+    let (x, y) = map_to_curve_svdw(u, &Fq2::zero(), &B_COEFF_FQ2, &SVDW_Z2, &SVDW_C2);
+
+    G2Affine {
+        x,
+        y,
+        infinity: false,
+    }
+    .into_projective()
+}
+
+fn hash_to_fq2(msg: &[u8], dst: &[u8], count: usize) -> Vec<Fq2> {
+    hash_to_field::<Fq>(msg, dst, 2 * count)
+        .chunks(2)
+        .map(|c| Fq2 { c0: c[0], c1: c[1] })
+        .collect()
+}
+
+fn clear_cofactor_g2(p: &G2) -> G2 {
+    p.into_affine().scale_by_cofactor()
+}
+
+/// Implements the BN254G1_XMD:SHA-256_SVDW_RO_ and
+/// BN254G1_XMD:SHA-256_SVDW_NU_ suites.
+impl HashToCurve for G1 {
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        let u = hash_to_field::<Fq>(msg, dst, 2);
+
+        let mut q = map_to_curve_g1(&u[0]);
+        q.add_assign(&map_to_curve_g1(&u[1]));
+        q
+    }
+
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        let u = hash_to_field::<Fq>(msg, dst, 1);
+
+        map_to_curve_g1(&u[0])
+    }
+}
+
+/// Implements the BN254G2_XMD:SHA-256_SVDW_RO_ and
+/// BN254G2_XMD:SHA-256_SVDW_NU_ suites. The cofactor is cleared by
+/// multiplication with h = 2q - r.
+impl HashToCurve for G2 {
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        let u = hash_to_fq2(msg, dst, 2);
+
+        let mut q = map_to_curve_g2(&u[0]);
+        q.add_assign(&map_to_curve_g2(&u[1]));
+        clear_cofactor_g2(&q)
+    }
+
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        let u = hash_to_fq2(msg, dst, 1);
+
+        clear_cofactor_g2(&map_to_curve_g2(&u[0]))
+    }
+}
+
+/// Computes SHA-256(dst || msg) reduced modulo q.
+fn sha256_to_fq(msg: &[u8], dst: &[u8]) -> Fq {
+    let digest = Sha256::new().chain(dst).chain(msg).finalize();
+
+    from_be_bytes_mod_order(&digest)
+}
+
+impl G1 {
+    /// Legacy try-and-increment hashing: starting from x = SHA-256(dst || msg)
+    /// mod q, x is incremented until x^3 + 3 is a square, and the point
+    /// (x, (x^3 + 3)^((q + 1) / 4)) is returned.
+    ///
+    /// This is neither constant time nor indifferentiable from a random
+    /// oracle; new protocols should use `hash_to_curve` instead.
+    pub fn hash_to_curve_try_and_increment(msg: &[u8], dst: &[u8]) -> G1 {
+        let mut x = sha256_to_fq(msg, dst);
+
+        loop {
+            let mut rhs = x;
+            rhs.square();
+            rhs.mul_assign(&x);
+            rhs.add_assign(&B_COEFF);
+
+            if let Some(y) = rhs.sqrt() {
+                return G1Affine {
+                    x,
+                    y,
+                    infinity: false,
+                }
+                .into_projective();
+            }
+
+            x.add_assign(&Fq::one());
+        }
+    }
+}
+
+#[cfg(test)]
+fn fq(hex: &str) -> Fq {
+    Fq::from_hex(hex).unwrap()
+}
+
+#[test]
+fn test_g1_hash_to_curve_vectors() {
+    let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+    let vectors: [(&[u8], &str, &str); 2] = [
+        (
+            b"",
+            "0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86",
+            "02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5",
+        ),
+        (
+            b"abc",
+            "23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1",
+            "04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d",
+        ),
+    ];
+
+    for &(msg, x, y) in vectors.iter() {
+        let p = G1::hash_to_curve(msg, dst).into_affine();
+        assert_eq!(p.x, fq(x));
+        assert_eq!(p.y, fq(y));
+    }
+}
+
+#[test]
+fn test_g1_encode_to_curve_vectors() {
+    let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_NU_";
+    let vectors: [(&[u8], &str, &str); 2] = [
+        (
+            b"",
+            "1bb8810e2ceaf04786d4efd216fc2820ddd9363712efc736ada11049d8af5925",
+            "1efbf8d54c60d865cce08437668ea30f5bf90d287dbd9b5af31da852915e8f11",
+        ),
+        (
+            b"abc",
+            "0da4a96147df1f35b0f820bd35c6fac3b80e8e320de7c536b1e054667b22c332",
+            "189bd3fbffe4c8740d6543754d95c790e44cd2d162858e3b733d2b8387983bb7",
+        ),
+    ];
+
+    for &(msg, x, y) in vectors.iter() {
+        let p = G1::encode_to_curve(msg, dst).into_affine();
+        assert_eq!(p.x, fq(x));
+        assert_eq!(p.y, fq(y));
+    }
+}
+
+#[cfg(test)]
+fn assert_in_g2(p: &G2) {
+    use super::Fr;
+
+    let p = p.into_affine();
+    assert!(!p.is_zero());
+
+    let mut lhs = p.y;
+    lhs.square();
+    let mut rhs = p.x;
+    rhs.square();
+    rhs.mul_assign(&p.x);
+    rhs.add_assign(&B_COEFF_FQ2);
+    assert_eq!(lhs, rhs);
+
+    assert!(p.mul(Fr::char()).is_zero());
+}
+
+#[test]
+fn test_g2_hash_to_curve() {
+    let dst = b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_";
+    let msgs: [&[u8]; 3] = [b"", b"abc", b"abcdef0123456789"];
+
+    for msg in msgs.iter() {
+        let p = G2::hash_to_curve(msg, dst);
+        assert_in_g2(&p);
+        assert_eq!(p, G2::hash_to_curve(msg, dst));
+        assert!(p != G2::hash_to_curve(msg, b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_NU_"));
+
+        assert_in_g2(&G2::encode_to_curve(msg, dst));
+    }
+}
+
+#[test]
+fn test_g1_try_and_increment() {
+    let msgs: [&[u8]; 3] = [b"", b"abc", b"abcdef0123456789"];
+
+    for msg in msgs.iter() {
+        let p = G1::hash_to_curve_try_and_increment(msg, b"").into_affine();
+
+        // x starts at SHA-256(msg) mod q and y is the root computed by the
+        // EVM modexp precompile.
+        let mut x = sha256_to_fq(msg, b"");
+        while x != p.x {
+            let mut rhs = x;
+            rhs.square();
+            rhs.mul_assign(&x);
+            rhs.add_assign(&B_COEFF);
+            assert!(rhs.sqrt().is_none());
+            x.add_assign(&Fq::one());
+        }
+
+        let mut rhs = p.x;
+        rhs.square();
+        rhs.mul_assign(&p.x);
+        rhs.add_assign(&B_COEFF);
+        assert_eq!(
+            p.y,
+            rhs.pow([
+                0x4f082305b61f3f52,
+                0x65e05aa45a1c72a3,
+                0x6e14116da0605617,
+                0x0c19139cb84c680a,
+            ])
+        );
+
+        assert!(p != G1::hash_to_curve_try_and_increment(msg, b"dst").into_affine());
+    }
+}
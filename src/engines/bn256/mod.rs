@@ -4,23 +4,34 @@ mod fq12;
 mod fq2;
 mod fq6;
 mod fr;
+mod hash_to_curve;
+mod precompiles;
 
 // #[cfg(test)]
 // mod tests;
 
 pub use self::ec::{
     G1, G1Affine, G1Compressed, G1Prepared, G1Uncompressed,
-    G2, G2Affine, G2Compressed, G2Prepared, G2Uncompressed,
+    G2, G2Affine, G2Compressed, G2Prepared, G2PreparedAffine, G2Uncompressed,
 };
 pub use self::fq::{Fq, FqRepr, FROBENIUS_COEFF_FQ6_C1, XI_TO_Q_MINUS_1_OVER_2};
-pub use self::fq12::Fq12;
+pub use self::fq12::{CompressedFq12, Fq12};
 pub use self::fq2::Fq2;
 pub use self::fq6::Fq6;
 pub use self::fr::{Fr, FrRepr};
+pub use self::precompiles::{ec_add, ec_mul, ec_pairing, PrecompileError};
 
-use crate::{CurveAffine, Engine};
+use self::ec::G2PreparedLines;
+use crate::prepared::{
+    mismatched_coefficients, read_fq, read_header, read_len, read_point, write_fq, write_header,
+    write_len, write_point, AFFINE_LINES, PROJECTIVE_LINES,
+};
+use crate::{generics::fields::batch_inversion, parallel, CurveAffine, Engine, Gt};
 
 use ff::{Field, ScalarEngine};
+use std::io::{self, Read, Write};
+use std::slice;
+use std::sync::OnceLock;
 
 #[derive(Clone, Debug)]
 pub struct Bn256;
@@ -53,6 +64,7 @@ impl Engine for Bn256 {
     type Fq = Fq;
     type Fqe = Fq2;
     type Fqk = Fq12;
+    type Gt = Gt<Self>;
 
     fn miller_loop<'a, I>(i: I) -> Self::Fqk
     where
@@ -66,67 +78,11 @@ impl Engine for Bn256 {
         let mut pairs = vec![];
         for &(p, q) in i {
             if !p.is_zero() && !q.is_zero() {
-                pairs.push((p, q.coeffs.iter()));
-            }
-        }
-
-        // Final steps of the line function on prepared coefficients
-        fn ell(f: &mut Fq12, coeffs: &(Fq2, Fq2, Fq2), p: &G1Affine) {
-            let mut c0 = coeffs.0;
-            let mut c1 = coeffs.1;
-
-            c0.c0.mul_assign(&p.y);
-            c0.c1.mul_assign(&p.y);
-
-            c1.c0.mul_assign(&p.x);
-            c1.c1.mul_assign(&p.x);
-
-            // Sparse multiplication in Fq12
-            f.mul_by_034(&c0, &c1, &coeffs.2);
-        }
-
-        let mut f = Fq12::one();
-        
-        for i in (1..SIX_U_PLUS_2_NAF.len()).rev() {
-            if i != SIX_U_PLUS_2_NAF.len() - 1 {
-                f.square();
-            }
-            for &mut (p, ref mut coeffs) in &mut pairs {
-                ell(&mut f, coeffs.next().unwrap(), &p.0);
-            }
-            let x = SIX_U_PLUS_2_NAF[i-1];
-            match x {
-                1 => {
-                        for &mut (p, ref mut coeffs) in &mut pairs {
-                            ell(&mut f, coeffs.next().unwrap(), &p.0);
-                        }
-                    }
-                -1 => {
-                        for &mut (p, ref mut coeffs) in &mut pairs {
-                            ell(&mut f, coeffs.next().unwrap(), &p.0);
-                        }
-                    }
-                _ => {
-                    continue
-                }
+                pairs.push((p, Lines::new(&q.lines)));
             }
         }
 
-        // two additional steps: for q1 and minus q2
-
-        for &mut (p, ref mut coeffs) in &mut pairs {
-            ell(&mut f, coeffs.next().unwrap(), &p.0);
-        }
-
-        for &mut (p, ref mut coeffs) in &mut pairs {
-            ell(&mut f, coeffs.next().unwrap(), &p.0);
-        }
-
-        for &mut (_p, ref mut coeffs) in &mut pairs {
-            assert_eq!(coeffs.next(), None);
-        }
-
-        f
+        parallel::product(pairs, 4, Self::miller_loop_lines)
     }
 
     fn final_exponentiation(r: &Fq12) -> Option<Fq12> {
@@ -142,7 +98,7 @@ impl Engine for Bn256 {
                 r.mul_assign(&f2);
 
                 fn exp_by_x(f: &mut Fq12, x: u64) {
-                    *f = f.pow(&[x]);
+                    *f = f.compressed_cyclotomic_exp(&[x]);
                 }
 
                 let x = BN_U;
@@ -225,6 +181,129 @@ impl Engine for Bn256 {
         }
     }
 
+    fn gt_generator() -> Fq12 {
+        static GENERATOR: OnceLock<Fq12> = OnceLock::new();
+
+        *GENERATOR.get_or_init(|| Self::pairing(G1Affine::one(), G2Affine::one()))
+    }
+}
+
+impl Bn256 {
+    /// Performs a Miller loop over `G2PreparedAffine` elements, which is
+    /// what `Engine::miller_loop` does for `G2Prepared` elements converted
+    /// from them.
+    pub fn miller_loop_affine<'a, I>(i: I) -> Fq12
+    where
+        I: IntoIterator<Item = &'a (&'a G1Prepared, &'a G2PreparedAffine)>,
+    {
+        let mut pairs = vec![];
+        for &(p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs.push((p, Lines::Affine(q.coeffs.iter())));
+            }
+        }
+
+        parallel::product(pairs, 4, Self::miller_loop_lines)
+    }
+
+    fn miller_loop_lines(mut pairs: Vec<(&G1Prepared, Lines)>) -> Fq12 {
+        let mut f = Fq12::one();
+        
+        for i in (1..SIX_U_PLUS_2_NAF.len()).rev() {
+            if i != SIX_U_PLUS_2_NAF.len() - 1 {
+                f.square();
+            }
+            for &mut (p, ref mut lines) in &mut pairs {
+                lines.ell(&mut f, &p.0);
+            }
+            let x = SIX_U_PLUS_2_NAF[i-1];
+            match x {
+                1 => {
+                        for &mut (p, ref mut lines) in &mut pairs {
+                            lines.ell(&mut f, &p.0);
+                        }
+                    }
+                -1 => {
+                        for &mut (p, ref mut lines) in &mut pairs {
+                            lines.ell(&mut f, &p.0);
+                        }
+                    }
+                _ => {
+                    continue
+                }
+            }
+        }
+
+        // two additional steps: for q1 and minus q2
+
+        for &mut (p, ref mut lines) in &mut pairs {
+            lines.ell(&mut f, &p.0);
+        }
+
+        for &mut (p, ref mut lines) in &mut pairs {
+            lines.ell(&mut f, &p.0);
+        }
+
+        for (_, lines) in &pairs {
+            assert!(lines.is_empty());
+        }
+
+        f
+    }
+}
+
+/// The remaining lines of a prepared element of G2, in either form.
+enum Lines<'a> {
+    Projective(slice::Iter<'a, (Fq2, Fq2, Fq2)>),
+    Affine(slice::Iter<'a, (Fq2, Fq2)>),
+}
+
+impl<'a> Lines<'a> {
+    fn new(lines: &'a G2PreparedLines) -> Self {
+        match *lines {
+            G2PreparedLines::Projective(ref coeffs) => Lines::Projective(coeffs.iter()),
+            G2PreparedLines::Affine(ref coeffs) => Lines::Affine(coeffs.iter()),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match *self {
+            Lines::Projective(ref coeffs) => coeffs.as_slice().is_empty(),
+            Lines::Affine(ref coeffs) => coeffs.as_slice().is_empty(),
+        }
+    }
+
+    /// Final steps of the line function on prepared coefficients: multiplies
+    /// `f` by the next line evaluated at `p`.
+    fn ell(&mut self, f: &mut Fq12, p: &G1Affine) {
+        match *self {
+            Lines::Projective(ref mut coeffs) => {
+                let coeffs = coeffs.next().unwrap();
+                let mut c0 = coeffs.0;
+                let mut c1 = coeffs.1;
+
+                c0.c0.mul_assign(&p.y);
+                c0.c1.mul_assign(&p.y);
+
+                c1.c0.mul_assign(&p.x);
+                c1.c1.mul_assign(&p.x);
+
+                // Sparse multiplication in Fq12
+                f.mul_by_034(&c0, &c1, &coeffs.2);
+            }
+            Lines::Affine(ref mut coeffs) => {
+                let &(a, b) = coeffs.next().unwrap();
+                let mut c0 = Fq2::zero();
+                c0.c0 = p.y;
+                let mut c1 = a;
+
+                c1.c0.mul_assign(&p.x);
+                c1.c1.mul_assign(&p.x);
+
+                f.mul_by_034(&c0, &c1, &b);
+            }
+        }
+    }
 }
 
 impl G2Prepared {
@@ -235,7 +314,8 @@ impl G2Prepared {
     pub fn from_affine(q: G2Affine) -> Self {
         if q.is_zero() {
             return G2Prepared {
-                coeffs: vec![],
+                p: q,
+                lines: G2PreparedLines::Projective(vec![]),
                 infinity: true,
             };
         }
@@ -461,12 +541,165 @@ impl G2Prepared {
         coeffs.push(addition_step(&mut r, &minusq2));
 
         G2Prepared {
+            p: q,
+            lines: G2PreparedLines::Projective(coeffs),
+            infinity: false,
+        }
+    }
+}
+
+impl G2PreparedAffine {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn from_affine(q: G2Affine) -> Self {
+        let coeffs = match G2Prepared::from_affine(q) {
+            G2Prepared {
+                lines: G2PreparedLines::Projective(coeffs),
+                infinity: false,
+                ..
+            } => coeffs,
+            _ => {
+                return G2PreparedAffine {
+                    p: q,
+                    coeffs: vec![],
+                    infinity: true,
+                }
+            }
+        };
+
+        // Each projective line is a multiple of y + a * x + b by its
+        // coefficient of y, which we invert all at once.
+        let mut scales: Vec<Fq2> = coeffs.iter().map(|c| c.0).collect();
+        batch_inversion(&mut scales);
+
+        let coeffs = coeffs
+            .iter()
+            .zip(scales.iter())
+            .map(|(&(_, mut a, mut b), scale)| {
+                a.mul_assign(scale);
+                b.mul_assign(scale);
+                (a, b)
+            })
+            .collect();
+
+        G2PreparedAffine {
+            p: q,
             coeffs,
             infinity: false,
         }
     }
 }
 
+impl From<G2PreparedAffine> for G2Prepared {
+    fn from(q: G2PreparedAffine) -> Self {
+        G2Prepared {
+            p: q.p,
+            lines: G2PreparedLines::Affine(q.coeffs),
+            infinity: q.infinity,
+        }
+    }
+}
+
+impl G2Prepared {
+    /// Writes a versioned encoding of this element, which `read` accepts.
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        match self.lines {
+            G2PreparedLines::Projective(ref coeffs) => {
+                write_header(&mut writer, PROJECTIVE_LINES)?;
+                write_point(&mut writer, &self.p)?;
+                write_len(&mut writer, coeffs.len())?;
+                for (c0, c1, c2) in coeffs {
+                    write_fq2(&mut writer, c0)?;
+                    write_fq2(&mut writer, c1)?;
+                    write_fq2(&mut writer, c2)?;
+                }
+            }
+            G2PreparedLines::Affine(ref coeffs) => {
+                write_header(&mut writer, AFFINE_LINES)?;
+                write_point(&mut writer, &self.p)?;
+                write_len(&mut writer, coeffs.len())?;
+                for (a, b) in coeffs {
+                    write_fq2(&mut writer, a)?;
+                    write_fq2(&mut writer, b)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads an element written by `write`. The point must be in G2 and the
+    /// lines must be the ones computed when preparing it, which is checked by
+    /// preparing it again.
+    pub fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        let form = read_header(&mut reader)?;
+        let p: G2Affine = read_point(&mut reader)?;
+
+        let count = if p.is_zero() { 0 } else { prepared_line_count() };
+        read_len(&mut reader, count)?;
+
+        let lines = if form == PROJECTIVE_LINES {
+            let mut coeffs = Vec::with_capacity(count);
+            for _ in 0..count {
+                coeffs.push((
+                    read_fq2(&mut reader)?,
+                    read_fq2(&mut reader)?,
+                    read_fq2(&mut reader)?,
+                ));
+            }
+
+            G2PreparedLines::Projective(coeffs)
+        } else {
+            let mut coeffs = Vec::with_capacity(count);
+            for _ in 0..count {
+                coeffs.push((read_fq2(&mut reader)?, read_fq2(&mut reader)?));
+            }
+
+            G2PreparedLines::Affine(coeffs)
+        };
+
+        let res = G2Prepared {
+            p,
+            lines,
+            infinity: p.is_zero(),
+        };
+
+        let expected = if form == PROJECTIVE_LINES {
+            p.prepare()
+        } else {
+            G2Prepared::from(G2PreparedAffine::from_affine(p))
+        };
+        if res != expected {
+            return Err(mismatched_coefficients());
+        }
+
+        Ok(res)
+    }
+}
+
+/// Returns the number of lines of a prepared element of G2 other than zero.
+fn prepared_line_count() -> usize {
+    let digits = &SIX_U_PLUS_2_NAF[..SIX_U_PLUS_2_NAF.len() - 1];
+
+    // a doubling per digit, an addition per non-zero digit and the two
+    // additions of q1 and minus q2
+    2 * digits.len() - digits.iter().filter(|d| **d == 0).count() + 2
+}
+
+fn write_fq2<W: Write>(writer: &mut W, c: &Fq2) -> io::Result<()> {
+    write_fq(writer, &c.c1)?;
+    write_fq(writer, &c.c0)
+}
+
+fn read_fq2<R: Read>(reader: &mut R) -> io::Result<Fq2> {
+    let c1 = read_fq(reader)?;
+    let c0 = read_fq(reader)?;
+
+    Ok(Fq2 { c0, c1 })
+}
+
 
 #[cfg(test)]
 use rand::{Rand, SeedableRng, XorShiftRng};
@@ -598,7 +831,78 @@ fn random_bilinearity_tests() {
     }
 }
 
+#[cfg(test)]
+impl crate::tests::engine::AffineMillerLoop for Bn256 {
+    type G2PreparedAffine = G2PreparedAffine;
+
+    fn prepare_affine(q: G2Affine) -> G2PreparedAffine {
+        G2PreparedAffine::from_affine(q)
+    }
+
+    fn is_zero(q: &G2PreparedAffine) -> bool {
+        q.is_zero()
+    }
+
+    fn into_prepared(q: G2PreparedAffine) -> G2Prepared {
+        G2Prepared::from(q)
+    }
+
+    fn miller_loop_affine(i: &[(&G1Prepared, &G2PreparedAffine)]) -> Fq12 {
+        Bn256::miller_loop_affine(i)
+    }
+}
+
+#[test]
+fn test_g2_prepared_affine() {
+    crate::tests::engine::affine_miller_loop_tests::<Bn256>();
+}
+
+#[test]
+fn test_prepared_encoding() {
+    use crate::{CurveProjective, EncodedPoint};
+    use rand::{Rand, SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    let q = G2::rand(&mut rng).into_affine();
+    let other = G2::rand(&mut rng).into_affine();
+
+    for prepared in vec![
+        q.prepare(),
+        G2Prepared::from(G2PreparedAffine::from_affine(q)),
+        G2Affine::zero().prepare(),
+    ] {
+        let mut encoded = vec![];
+        prepared.write(&mut encoded).unwrap();
+        assert_eq!(G2Prepared::read(&encoded[..]).unwrap(), prepared);
+
+        // an unknown version
+        let mut bad = encoded.clone();
+        bad[0] = 2;
+        assert!(G2Prepared::read(&bad[..]).is_err());
+
+        // truncated
+        assert!(G2Prepared::read(&encoded[..encoded.len() - 1]).is_err());
+
+        // the lines of one point next to another
+        let mut bad = encoded.clone();
+        bad[2..2 + G2Uncompressed::size()].copy_from_slice(other.into_uncompressed().as_ref());
+        assert!(G2Prepared::read(&bad[..]).is_err());
+
+        // a corrupted last line
+        let mut bad = encoded.clone();
+        *bad.last_mut().unwrap() ^= 1;
+        assert!(G2Prepared::read(&bad[..]).is_err());
+    }
+}
+
 #[test]
 fn bn256_engine_tests() {
     crate::tests::engine::engine_tests::<Bn256>();
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn bn256_serde_tests() {
+    crate::tests::serialization::serde_tests::<Bn256>();
+}
@@ -0,0 +1,786 @@
+//! The `ecAdd`, `ecMul` and `ecPairing` precompiles of Ethereum, as specified
+//! by EIP-196 and EIP-197.
+//!
+//! Field elements are 32-byte big-endian integers, which must be less than
+//! the modulus. A point of G1 is its x and y coordinates, and a point of G2
+//! is its x and y coordinates with the `c1` coefficient of each written
+//! before `c0`. There are no flag bits: `(0, 0)` stands for the point at
+//! infinity.
+
+use super::{Bn256, Fq, Fq2, FqRepr, Fr, FrRepr, G1Affine, G2Affine};
+use crate::{CurveAffine, CurveProjective, Engine, GroupDecodingError};
+
+use ff::{PrimeField, PrimeFieldRepr};
+use std::error::Error;
+use std::fmt;
+
+/// The length of an encoded field element.
+const FQ_LEN: usize = 32;
+
+/// The length of an encoded point of G1.
+const G1_LEN: usize = 2 * FQ_LEN;
+
+/// The length of an encoded point of G2.
+const G2_LEN: usize = 4 * FQ_LEN;
+
+/// The length of a pair of points in the input of `ec_pairing`.
+const PAIR_LEN: usize = G1_LEN + G2_LEN;
+
+/// An error in the input of a precompile, on which the call fails.
+#[derive(Debug)]
+pub enum PrecompileError {
+    /// The input of `ec_pairing` is not a whole number of pairs.
+    InvalidInputLength(usize),
+    /// A point is not canonically encoded, not on the curve or, for G2, not
+    /// in the prime order subgroup.
+    InvalidPoint(GroupDecodingError),
+}
+
+impl From<GroupDecodingError> for PrecompileError {
+    fn from(e: GroupDecodingError) -> PrecompileError {
+        PrecompileError::InvalidPoint(e)
+    }
+}
+
+impl Error for PrecompileError {
+    fn description(&self) -> &str {
+        match *self {
+            PrecompileError::InvalidInputLength(_) => "input length is not a multiple of 192",
+            PrecompileError::InvalidPoint(_) => "invalid point",
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        match *self {
+            PrecompileError::InvalidInputLength(_) => None,
+            PrecompileError::InvalidPoint(ref e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for PrecompileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            PrecompileError::InvalidInputLength(len) => {
+                write!(f, "input length {} is not a multiple of {}", len, PAIR_LEN)
+            }
+            PrecompileError::InvalidPoint(ref e) => write!(f, "invalid point: {}", e),
+        }
+    }
+}
+
+/// Returns the first `N` bytes of `input`, padded with zeros if it is
+/// shorter, like the input of a precompile is read by the EVM.
+fn padded<const N: usize>(input: &[u8]) -> [u8; N] {
+    let mut res = [0u8; N];
+    let len = input.len().min(N);
+    res[..len].copy_from_slice(&input[..len]);
+
+    res
+}
+
+fn read_fq(bytes: &[u8], coordinate: &'static str) -> Result<Fq, GroupDecodingError> {
+    let mut repr = FqRepr::default();
+    repr.read_be(bytes).unwrap();
+
+    Fq::from_repr(repr).map_err(|e| GroupDecodingError::CoordinateDecodingError(coordinate, e))
+}
+
+fn read_fq2(bytes: &[u8], coordinate: &'static str) -> Result<Fq2, GroupDecodingError> {
+    Ok(Fq2 {
+        c1: read_fq(&bytes[..FQ_LEN], coordinate)?,
+        c0: read_fq(&bytes[FQ_LEN..], coordinate)?,
+    })
+}
+
+fn read_g1(bytes: &[u8]) -> Result<G1Affine, GroupDecodingError> {
+    let x = read_fq(&bytes[..FQ_LEN], "x coordinate")?;
+    let y = read_fq(&bytes[FQ_LEN..G1_LEN], "y coordinate")?;
+
+    G1Affine::from_xy_checked(x, y)
+}
+
+fn read_g2(bytes: &[u8]) -> Result<G2Affine, GroupDecodingError> {
+    let x = read_fq2(&bytes[..2 * FQ_LEN], "x coordinate")?;
+    let y = read_fq2(&bytes[2 * FQ_LEN..G2_LEN], "y coordinate")?;
+
+    G2Affine::from_xy_checked(x, y)
+}
+
+fn write_g1(p: &G1Affine) -> [u8; G1_LEN] {
+    let mut res = [0u8; G1_LEN];
+    p.x().into_repr().write_be(&mut res[..FQ_LEN]).unwrap();
+    p.y().into_repr().write_be(&mut res[FQ_LEN..]).unwrap();
+
+    res
+}
+
+/// The `ecAdd` precompile: adds two points of G1.
+pub fn ec_add(input: &[u8]) -> Result<[u8; G1_LEN], PrecompileError> {
+    let input = padded::<{ 2 * G1_LEN }>(input);
+
+    let mut p = read_g1(&input[..G1_LEN])?.into_projective();
+    p.add_assign_mixed(&read_g1(&input[G1_LEN..])?);
+
+    Ok(write_g1(&p.into_affine()))
+}
+
+/// The `ecMul` precompile: multiplies a point of G1 by a 256-bit scalar,
+/// which need not be less than the group order.
+pub fn ec_mul(input: &[u8]) -> Result<[u8; G1_LEN], PrecompileError> {
+    let input = padded::<{ G1_LEN + 32 }>(input);
+
+    let p = read_g1(&input[..G1_LEN])?;
+
+    let mut scalar = FrRepr::default();
+    scalar.read_be(&input[G1_LEN..]).unwrap();
+    let r = Fr::char();
+    while scalar >= r {
+        scalar.sub_noborrow(&r);
+    }
+
+    Ok(write_g1(&p.mul(scalar).into_affine()))
+}
+
+/// The `ecPairing` precompile: checks that the product of the pairings of
+/// the given pairs of points is one. The output is a 32-byte big-endian one
+/// if it is, and zero otherwise, and is one for an empty input.
+pub fn ec_pairing(input: &[u8]) -> Result<[u8; 32], PrecompileError> {
+    if !input.len().is_multiple_of(PAIR_LEN) {
+        return Err(PrecompileError::InvalidInputLength(input.len()));
+    }
+
+    let mut prepared = Vec::with_capacity(input.len() / PAIR_LEN);
+    for pair in input.chunks(PAIR_LEN) {
+        let p = read_g1(&pair[..G1_LEN])?;
+        let q = read_g2(&pair[G1_LEN..])?;
+        prepared.push((p.prepare(), q.prepare()));
+    }
+
+    let terms: Vec<_> = prepared.iter().map(|(p, q)| (p, q)).collect();
+
+    let mut res = [0u8; 32];
+    res[31] = Bn256::pairing_product_is_one(&terms) as u8;
+
+    Ok(res)
+}
+
+#[cfg(test)]
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_ec_add() {
+    // The vectors of go-ethereum's bn256Add.json
+    let vectors = [
+        (
+            "chfast1",
+            "18b18acfb4c2c30276db5411368e7185b311dd124691610c5d3b74034e093dc9\
+             063c909c4720840cb5134cb9f59fa749755796819658d32efc0d288198f37266\
+             07c2b7f58a84bd6145f00c9c2bc0bb1a187f20ff2c92963a88019e7c6a014eed\
+             06614e20c147e940f2d70da3f74c9a17df361706a4485c742bd6788478fa17d7",
+            "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703\
+             301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915",
+        ),
+        (
+            "chfast2",
+            "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703\
+             301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915\
+             18b18acfb4c2c30276db5411368e7185b311dd124691610c5d3b74034e093dc9\
+             063c909c4720840cb5134cb9f59fa749755796819658d32efc0d288198f37266",
+            "2bd3e6d0f3b142924f5ca7b49ce5b9d54c4703d7ae5648e61d02268b1a0a9fb7\
+             21611ce0a6af85915e2f1d70300909ce2e49dfad4a4619c8390cae66cefdb204",
+        ),
+        (
+            "cdetrio1",
+            "",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "cdetrio2",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "cdetrio3",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "cdetrio4",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "cdetrio5",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "cdetrio6",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002",
+        ),
+        (
+            "cdetrio7",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002",
+        ),
+        (
+            "cdetrio8",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002",
+        ),
+        (
+            "cdetrio9",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002",
+        ),
+        (
+            "cdetrio10",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002",
+        ),
+        (
+            "cdetrio11",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002",
+            "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3\
+             15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4",
+        ),
+        (
+            "cdetrio12",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+            "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3\
+             15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4",
+        ),
+        (
+            "cdetrio13",
+            "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9\
+             01e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c\
+             039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869\
+             073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98",
+            "15bf2bb17880144b5d1cd2b1f46eff9d617bffd1ca57c37fb5a49bd84e53cf66\
+             049c797f9ce0d17083deb32b5e36f2ea2a212ee036598dd7624c168993d1355f",
+        ),
+        (
+            "cdetrio14",
+            "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9\
+             01e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c\
+             17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9\
+             2e83f8d734803fc370eba25ed1f6b8768bd6d83887b87165fc2434fe11a830cb",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+    ];
+
+    for (name, input, output) in vectors.iter() {
+        assert_eq!(&ec_add(&from_hex(input)).unwrap()[..], &from_hex(output)[..], "{}", name);
+    }
+
+    let invalid = [
+        (
+            "x of the first point is the modulus plus one",
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd48\
+             0000000000000000000000000000000000000000000000000000000000000002",
+        ),
+        (
+            "the second point is not on the curve",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000003",
+        ),
+    ];
+
+    for (name, input) in invalid.iter() {
+        assert!(ec_add(&from_hex(input)).is_err(), "{}", name);
+    }
+
+    // Surplus bytes are ignored
+    let mut input = from_hex(vectors[0].1);
+    input.extend_from_slice(&[0xff; 10]);
+    assert_eq!(&ec_add(&input).unwrap()[..], &from_hex(vectors[0].2)[..]);
+
+    // (1, 3) is not on the curve
+    let input = from_hex(
+        "0000000000000000000000000000000000000000000000000000000000000001\
+         0000000000000000000000000000000000000000000000000000000000000003",
+    );
+    match ec_add(&input) {
+        Err(PrecompileError::InvalidPoint(GroupDecodingError::NotOnCurve)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    // y = 2 + p is not canonical
+    let input = from_hex(
+        "0000000000000000000000000000000000000000000000000000000000000001\
+         30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd49",
+    );
+    match ec_add(&input) {
+        Err(PrecompileError::InvalidPoint(GroupDecodingError::CoordinateDecodingError(
+            "y coordinate",
+            _,
+        ))) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn test_ec_mul() {
+    // The vectors of go-ethereum's bn256ScalarMul.json
+    let vectors = [
+        (
+            "chfast1",
+            "2bd3e6d0f3b142924f5ca7b49ce5b9d54c4703d7ae5648e61d02268b1a0a9fb7\
+             21611ce0a6af85915e2f1d70300909ce2e49dfad4a4619c8390cae66cefdb204\
+             00000000000000000000000000000000000000000000000011138ce750fa15c2",
+            "070a8d6a982153cae4be29d434e8faef8a47b274a053f5a4ee2a6c9c13c31e5c\
+             031b8ce914eba3a9ffb989f9cdd5b0f01943074bf4f0f315690ec3cec6981afc",
+        ),
+        (
+            "chfast2",
+            "070a8d6a982153cae4be29d434e8faef8a47b274a053f5a4ee2a6c9c13c31e5c\
+             031b8ce914eba3a9ffb989f9cdd5b0f01943074bf4f0f315690ec3cec6981afc\
+             30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46",
+            "025a6f4181d2b4ea8b724290ffb40156eb0adb514c688556eb79cdea0752c2bb\
+             2eff3f31dea215f1eb86023a133a996eb6300b44da664d64251d05381bb8a02e",
+        ),
+        (
+            "chfast3",
+            "025a6f4181d2b4ea8b724290ffb40156eb0adb514c688556eb79cdea0752c2bb\
+             2eff3f31dea215f1eb86023a133a996eb6300b44da664d64251d05381bb8a02e\
+             183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea3",
+            "14789d0d4a730b354403b5fac948113739e276c23e0258d8596ee72f9cd9d323\
+             0af18a63153e0ec25ff9f2951dd3fa90ed0197bfef6e2a1a62b5095b9d2b4a27",
+        ),
+        (
+            "cdetrio1",
+            "1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe3\
+             1a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f6\
+             ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "2cde5879ba6f13c0b5aa4ef627f159a3347df9722efce88a9afbb20b763b4c41\
+             1aa7e43076f6aee272755a7f9b84832e71559ba0d2e0b17d5f9f01755e5b0d11",
+        ),
+        (
+            "cdetrio2",
+            "1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe3\
+             1a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f6\
+             30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000",
+            "1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe3\
+             163511ddc1c3f25d396745388200081287b3fd1472d8339d5fecb2eae0830451",
+        ),
+        (
+            "cdetrio3",
+            "1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe3\
+             1a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f6\
+             30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "cdetrio4",
+            "1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe3\
+             1a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f6\
+             0000000000000000000000000000000000000000000000000000000000000009",
+            "1dbad7d39dbc56379f78fac1bca147dc8e66de1b9d183c7b167351bfe0aeab74\
+             2cd757d51289cd8dbd0acf9e673ad67d0f0a89f912af47ed1be53664f5692575",
+        ),
+        (
+            "cdetrio5",
+            "1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe3\
+             1a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f6\
+             0000000000000000000000000000000000000000000000000000000000000001",
+            "1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe3\
+             1a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f6",
+        ),
+        (
+            "cdetrio6",
+            "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9\
+             01e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c\
+             ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "29e587aadd7c06722aabba753017c093f70ba7eb1f1c0104ec0564e7e3e21f60\
+             22b1143f6a41008e7755c71c3d00b6b915d386de21783ef590486d8afa8453b1",
+        ),
+        (
+            "cdetrio7",
+            "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9\
+             01e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c\
+             30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000",
+            "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9\
+             2e83f8d734803fc370eba25ed1f6b8768bd6d83887b87165fc2434fe11a830cb",
+        ),
+        (
+            "cdetrio8",
+            "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9\
+             01e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c\
+             30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "cdetrio9",
+            "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9\
+             01e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c\
+             0000000000000000000000000000000000000000000000000000000000000009",
+            "228e687a379ba154554040f8821f4e41ee2be287c201aa9c3bc02c9dd12f1e69\
+             1e0fd6ee672d04cfd924ed8fdc7ba5f2d06c53c1edc30f65f2af5a5b97f0a76a",
+        ),
+        (
+            "cdetrio10",
+            "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9\
+             01e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c\
+             0000000000000000000000000000000000000000000000000000000000000001",
+            "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9\
+             01e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c",
+        ),
+        (
+            "cdetrio11",
+            "039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869\
+             073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98\
+             ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "00a1a234d08efaa2616607e31eca1980128b00b415c845ff25bba3afcb81dc00\
+             242077290ed33906aeb8e42fd98c41bcb9057ba03421af3f2d08cfc441186024",
+        ),
+        (
+            "cdetrio12",
+            "039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869\
+             073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98\
+             30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000",
+            "039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869\
+             2929ee761a352600f54921df9bf472e66217e7bb0cee9032e00acc86b3c8bfaf",
+        ),
+        (
+            "cdetrio13",
+            "039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869\
+             073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98\
+             30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "cdetrio14",
+            "039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869\
+             073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98\
+             0000000000000000000000000000000000000000000000000000000000000009",
+            "19f75b9dd68c080a688774a6213f131e3052bd353a304a189d7a2ee367e3c258\
+             2612f545fb9fc89fde80fd81c68fc7dcb27fea5fc124eeda69433cf5c46d2d7f",
+        ),
+        (
+            "cdetrio15",
+            "039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869\
+             073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98\
+             0000000000000000000000000000000000000000000000000000000000000001",
+            "039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869\
+             073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98",
+        ),
+    ];
+
+    for (name, input, output) in vectors.iter() {
+        assert_eq!(&ec_mul(&from_hex(input)).unwrap()[..], &from_hex(output)[..], "{}", name);
+    }
+
+    let invalid = [
+        (
+            "x is the modulus",
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000001",
+        ),
+        (
+            "the point is not on the curve",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000003\
+             0000000000000000000000000000000000000000000000000000000000000002",
+        ),
+    ];
+
+    for (name, input) in invalid.iter() {
+        assert!(ec_mul(&from_hex(input)).is_err(), "{}", name);
+    }
+
+    // A missing scalar is zero
+    let point = &vectors[0].1[..2 * G1_LEN];
+    assert_eq!(&ec_mul(&from_hex(point)).unwrap()[..], &[0u8; 64][..]);
+
+    let mut input = from_hex(point);
+    input[63] ^= 1;
+    match ec_mul(&input) {
+        Err(PrecompileError::InvalidPoint(GroupDecodingError::NotOnCurve)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn test_ec_pairing() {
+    use ff::Field;
+
+    let g1 = "0000000000000000000000000000000000000000000000000000000000000001\
+              0000000000000000000000000000000000000000000000000000000000000002";
+    let neg_g1 = "0000000000000000000000000000000000000000000000000000000000000001\
+                  30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45";
+    let g2 = "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+              1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+              090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+              12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa";
+    let zero_g1 = "00".repeat(G1_LEN);
+
+    // The vectors of go-ethereum's bn256Pairing.json
+    let vectors = [
+        (
+            "jeff1",
+            "1c76476f4def4bb94541d57ebba1193381ffa7aa76ada664dd31c16024c43f59\
+             3034dd2920f673e204fee2811c678745fc819b55d3e9d294e45c9b03a76aef41\
+             209dd15ebff5d46c4bd888e51a93cf99a7329636c63514396b4a452003a35bf7\
+             04bf11ca01483bfa8b34b43561848d28905960114c8ac04049af4b6315a41678\
+             2bb8324af6cfc93537a2ad1a445cfd0ca2a71acd7ac41fadbf933c2a51be344d\
+             120a2a4cf30c1bf9845f20c6fe39e07ea2cce61f0c9bb048165fe5e4de877550\
+             111e129f1cf1097710d41c4ac70fcdfa5ba2023c6ff1cbeac322de49d1b6df7c\
+             2032c61a830e3c17286de9462bf242fca2883585b93870a73853face6a6bf411\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        ),
+        (
+            "jeff2",
+            "2eca0c7238bf16e83e7a1e6c5d49540685ff51380f309842a98561558019fc02\
+             03d3260361bb8451de5ff5ecd17f010ff22f5c31cdf184e9020b06fa5997db84\
+             1213d2149b006137fcfb23036606f848d638d576a120ca981b5b1a5f9300b3ee\
+             2276cf730cf493cd95d64677bbb75fc42db72513a4c1e387b476d056f80aa75f\
+             21ee6226d31426322afcda621464d0611d226783262e21bb3bc86b537e986237\
+             096df1f82dff337dd5972e32a8ad43e28a78a96a823ef1cd4debe12b6552ea5f\
+             06967a1237ebfeca9aaae0d6d0bab8e28c198c5a339ef8a2407e31cdac516db9\
+             22160fa257a5fd5b280642ff47b65eca77e626cb685c84fa6d3b6882a283ddd1\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        ),
+        (
+            "jeff3",
+            "0f25929bcb43d5a57391564615c9e70a992b10eafa4db109709649cf48c50dd2\
+             16da2f5cb6be7a0aa72c440c53c9bbdfec6c36c7d515536431b3a865468acbba\
+             2e89718ad33c8bed92e210e81d1853435399a271913a6520736a4729cf0d51eb\
+             01a9e2ffa2e92599b68e44de5bcf354fa2642bd4f26b259daa6f7ce3ed57aeb3\
+             14a9a87b789a58af499b314e13c3d65bede56c07ea2d418d6874857b70763713\
+             178fb49a2d6cd347dc58973ff49613a20757d0fcc22079f9abd10c3baee24590\
+             1b9e027bd5cfc2cb5db82d4dc9677ac795ec500ecd47deee3b5da006d6d049b8\
+             11d7511c78158de484232fc68daf8a45cf217d1c2fae693ff5871e8752d73b21\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        ),
+        (
+            "jeff6",
+            "1c76476f4def4bb94541d57ebba1193381ffa7aa76ada664dd31c16024c43f59\
+             3034dd2920f673e204fee2811c678745fc819b55d3e9d294e45c9b03a76aef41\
+             209dd15ebff5d46c4bd888e51a93cf99a7329636c63514396b4a452003a35bf7\
+             04bf11ca01483bfa8b34b43561848d28905960114c8ac04049af4b6315a41678\
+             2bb8324af6cfc93537a2ad1a445cfd0ca2a71acd7ac41fadbf933c2a51be344d\
+             120a2a4cf30c1bf9845f20c6fe39e07ea2cce61f0c9bb048165fe5e4de877550\
+             111e129f1cf1097710d41c4ac70fcdfa5ba2023c6ff1cbeac322de49d1b6df7c\
+             103188585e2364128fe25c70558f1560f4f9350baf3959e603cc91486e110936\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "empty_data",
+            "",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        ),
+        (
+            "one_point",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "two_point_match_2",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        ),
+        (
+            "two_point_match_3",
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        ),
+    ];
+
+    for (name, input, output) in vectors.iter() {
+        assert_eq!(&ec_pairing(&from_hex(input)).unwrap()[..], &from_hex(output)[..], "{}", name);
+    }
+
+    // ten_point_match_1 and ten_point_match_2 repeat two_point_match_3 and
+    // two_point_match_2
+    let one = from_hex("0000000000000000000000000000000000000000000000000000000000000001");
+    let zero_g2 = "00".repeat(G2_LEN);
+    for pairs in [[&zero_g1, g2, g1, &zero_g2], [g1, g2, neg_g1, g2]].iter() {
+        let input = from_hex(&pairs.concat().repeat(5));
+        assert_eq!(&ec_pairing(&input).unwrap()[..], &one[..]);
+    }
+
+    let invalid = [
+        (
+            "a byte short",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7d",
+        ),
+        (
+            "a byte over",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa\
+             00",
+        ),
+        (
+            "a point of G1 not on the curve",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000003\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+        ),
+        (
+            "x.c1 of the point of G2 is the modulus",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+        ),
+        (
+            "a point of G2 not on the twist",
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7dab",
+        ),
+    ];
+
+    for (name, input) in invalid.iter() {
+        assert!(ec_pairing(&from_hex(input)).is_err(), "{}", name);
+    }
+
+    // The generator of G2 with c0 before c1
+    let swapped = [&g2[64..128], &g2[..64], &g2[192..], &g2[128..192]].concat();
+    match ec_pairing(&from_hex(&[g1, &swapped].concat())) {
+        Err(PrecompileError::InvalidPoint(GroupDecodingError::NotOnCurve)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    match ec_pairing(&from_hex(g1)) {
+        Err(PrecompileError::InvalidInputLength(64)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    // A point of the twist, which is not in G2 since the cofactor is large
+    let mut x = Fq2::one();
+    let q = loop {
+        if let Some(q) = G2Affine::get_point_from_x(x, false) {
+            break q;
+        }
+        x.c1.add_assign(&Fq::one());
+    };
+    let mut input = from_hex(g1);
+    for c in [q.x().c1, q.x().c0, q.y().c1, q.y().c0].iter() {
+        c.into_repr().write_be(&mut input).unwrap();
+    }
+    match ec_pairing(&input) {
+        Err(PrecompileError::InvalidPoint(GroupDecodingError::NotInSubgroup)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+}
@@ -279,6 +279,11 @@ pub const FROBENIUS_COEFF_FQ4_C1: [Fq; 4] = [
 #[PrimeFieldGenerator = "17"]
 pub struct Fq(FqRepr);
 
+ct_prime_field_impl!(Fq, FqRepr);
+
+#[cfg(feature = "serde")]
+serde_impl!(Fq, crate::serialization::serialize_field, crate::serialization::deserialize_field);
+
 // This is negative one wrt the limbs, mod q
 // -((2**756) mod q) mod q
 #[allow(dead_code)]
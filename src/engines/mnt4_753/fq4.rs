@@ -1,8 +1,8 @@
 extern crate std;
 
 use super::{
-    fq::{Fq, FROBENIUS_COEFF_FQ4_C1},
-    fq2::{ Fq2Extension },
+    fq::{Fq, FqRepr, FROBENIUS_COEFF_FQ4_C1},
+    fq2::{ Fq2, Fq2Extension },
 };
 
 use crate::{
@@ -12,8 +12,13 @@ use crate::{
             Fp4Extension,
         },
     },
+    gt::{read_fq, write_fq},
+    TargetField,
 };
 
+use ff::PrimeFieldDecodingError;
+use std::mem;
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Fq4Extension();
 
@@ -25,8 +30,45 @@ impl Fp4Extension for Fq4Extension {
 
 pub type Fq4 = Fp4<Fq4Extension>;
 
-#[cfg(test)]
+const FQ_SIZE: usize = mem::size_of::<FqRepr>();
+
+impl TargetField for Fq4 {
+    type Subfield = Fq2;
+
+    const SUBFIELD_SIZE: usize = 2 * FQ_SIZE;
+
+    fn from_subfield(c0: Fq2, c1: Fq2) -> Self {
+        Fq4 { c0, c1 }
+    }
+
+    fn into_subfield(self) -> (Fq2, Fq2) {
+        (self.c0, self.c1)
+    }
+
+    fn mul_subfield_by_nonresidue(c: &mut Fq2) {
+        Fq4Extension::mul_by_nonresidue(c);
+    }
+
+    fn write_subfield(c: &Fq2, bytes: &mut [u8]) {
+        write_fq(&c.c1, &mut bytes[..FQ_SIZE]);
+        write_fq(&c.c0, &mut bytes[FQ_SIZE..]);
+    }
 
+    fn read_subfield(bytes: &[u8]) -> Result<Fq2, PrimeFieldDecodingError> {
+        let c1 = read_fq(&bytes[..FQ_SIZE])?;
+        let c0 = read_fq(&bytes[FQ_SIZE..])?;
+
+        Ok(Fq2 { c0, c1 })
+    }
+
+    fn conjugate(&mut self) {
+        Fq4::conjugate(self);
+    }
+
+    fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        Fq4::cyclotomic_exp(self, exp)
+    }
+}
 
 #[test]
 fn fq4_field_tests() {
@@ -35,3 +77,8 @@ fn fq4_field_tests() {
     crate::tests::field::random_field_tests::<Fq4>();
     crate::tests::field::random_frobenius_tests::<Fq4, _>(super::fq::Fq::char(), 13);
 }
+
+#[test]
+fn fq4_torus_tests() {
+    crate::tests::torus::t2_tests::<Fq4>();
+}
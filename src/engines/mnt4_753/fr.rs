@@ -5,6 +5,11 @@ use ff::{Field, PrimeField, PrimeFieldRepr};
 #[PrimeFieldGenerator = "17"]
 pub struct Fr(FrRepr);
 
+ct_prime_field_impl!(Fr, FrRepr);
+
+#[cfg(feature = "serde")]
+serde_impl!(Fr, crate::serialization::serialize_field, crate::serialization::deserialize_field);
+
 #[allow(dead_code)]
 fn print_repr(name: &str, s: &str) {
     let x = Fr::from_str(s).unwrap();
@@ -83,6 +83,109 @@ macro_rules! curve_impl {
             }
         }
 
+        impl ConditionallySelectable for $affine {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                $affine {
+                    x: $basefield::conditional_select(&a.x, &b.x, choice),
+                    y: $basefield::conditional_select(&a.y, &b.y, choice),
+                    infinity: u8::conditional_select(&(a.infinity as u8), &(b.infinity as u8), choice) != 0,
+                }
+            }
+        }
+
+        impl ConstantTimeEq for $affine {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                let self_is_zero = Choice::from(self.infinity as u8);
+                let other_is_zero = Choice::from(other.infinity as u8);
+
+                (self_is_zero & other_is_zero)
+                    | (!self_is_zero & !other_is_zero & self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y))
+            }
+        }
+
+        impl CtCurveAffine for $affine {
+            fn conditional_negate(&mut self, choice: Choice) {
+                // the point at infinity keeps its y coordinate, as in `negate`
+                let infinity = Choice::from(self.infinity as u8);
+                self.y.conditional_negate(choice & !infinity);
+            }
+
+            fn ct_mul<S: Into<<Self::Scalar as PrimeField>::Repr>>(&self, scalar: S) -> Self {
+                let (x, y, infinity) = crate::ct::mul(
+                    &self.x,
+                    &self.y,
+                    Choice::from(self.infinity as u8),
+                    &$affine::get_coeff_a(),
+                    &$affine::get_coeff_b(),
+                    scalar.into(),
+                );
+
+                $affine::conditional_select(&$affine { x, y, infinity: false }, &$affine::zero(), infinity)
+            }
+        }
+
+        impl ConditionallySelectable for $projective {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                $projective {
+                    x: $basefield::conditional_select(&a.x, &b.x, choice),
+                    y: $basefield::conditional_select(&a.y, &b.y, choice),
+                    z: $basefield::conditional_select(&a.z, &b.z, choice),
+                }
+            }
+        }
+
+        impl ConstantTimeEq for $projective {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                // The same comparison as `eq`, without returning early.
+                let mut z1 = self.z;
+                z1.square();
+                let mut z2 = other.z;
+                z2.square();
+
+                let mut x1 = self.x;
+                x1.mul_assign(&z2);
+                let mut x2 = other.x;
+                x2.mul_assign(&z1);
+
+                z1.mul_assign(&self.z);
+                z2.mul_assign(&other.z);
+
+                let mut y1 = self.y;
+                y1.mul_assign(&z2);
+                let mut y2 = other.y;
+                y2.mul_assign(&z1);
+
+                let self_is_zero = self.z.ct_eq(&$basefield::zero());
+                let other_is_zero = other.z.ct_eq(&$basefield::zero());
+
+                (self_is_zero & other_is_zero)
+                    | (!self_is_zero & !other_is_zero & x1.ct_eq(&x2) & y1.ct_eq(&y2))
+            }
+        }
+
+        impl CtCurveProjective for $projective {
+            fn conditional_negate(&mut self, choice: Choice) {
+                self.y.conditional_negate(choice);
+            }
+
+            fn ct_into_affine(&self) -> $affine {
+                let z_inv = self.z.ct_inverse();
+                let infinity = z_inv.is_none();
+                let z_inv = z_inv.unwrap_or($basefield::zero());
+
+                // (X / Z^2, Y / Z^3)
+                let mut z_inv2 = z_inv;
+                z_inv2.square();
+                let mut x = self.x;
+                x.mul_assign(&z_inv2);
+                z_inv2.mul_assign(&z_inv);
+                let mut y = self.y;
+                y.mul_assign(&z_inv2);
+
+                $affine::conditional_select(&$affine { x, y, infinity: false }, &$affine::zero(), infinity)
+            }
+        }
+
         impl $affine {
             #[inline(always)]
             fn mul_bits<S: AsRef<[u64]>>(&self, bits: BitIterator<S>) -> $projective {
@@ -124,28 +227,92 @@ macro_rules! curve_impl {
                 })
             }
 
-            #[inline(always)]
-            fn is_on_curve(&self) -> bool {
-                if self.is_zero() {
-                    true
+            /// Constructs a point from its affine coordinates, checking that it
+            /// is on the curve and in the prime order subgroup. The coordinates
+            /// (0, 0) denote the point at infinity.
+            pub fn from_xy_checked(x: $basefield, y: $basefield) -> Result<Self, GroupDecodingError> {
+                let p = Self::from_xy_unchecked(x, y);
+
+                if !p.is_on_curve() {
+                    Err(GroupDecodingError::NotOnCurve)
+                } else if !p.is_in_correct_subgroup_assuming_on_curve() {
+                    Err(GroupDecodingError::NotInSubgroup)
                 } else {
-                    // Check that the point is on the curve
-                    let mut y2 = self.y;
-                    y2.square();
+                    Ok(p)
+                }
+            }
 
-                    let mut x3axb = self.x;
-                    x3axb.square(); // x^2
-                    x3axb.add_assign(&Self::get_coeff_a()); // x^2 + a
-                    x3axb.mul_assign(&self.x); // x^3 + ax
-                    x3axb.add_assign(&Self::get_coeff_b()); // x^3 + ax + b
+            /// Constructs a point from its affine coordinates without checking
+            /// that it is on the curve or in the prime order subgroup. The
+            /// coordinates (0, 0) denote the point at infinity.
+            ///
+            /// If the point is invalid, this can break API invariants, so
+            /// caution is strongly encouraged.
+            pub fn from_xy_unchecked(x: $basefield, y: $basefield) -> Self {
+                if x.is_zero() && y.is_zero() {
+                    Self::zero()
+                } else {
+                    $affine {
+                        x,
+                        y,
+                        infinity: false
+                    }
+                }
+            }
 
-                    y2 == x3axb
+            /// Constructs the point with the given x-coordinate, checking that
+            /// it is in the prime order subgroup. If and only if `greatest` is
+            /// set will the lexicographically largest y-coordinate be selected,
+            /// as with the sign flag of the compressed encoding.
+            pub fn from_x_and_sign(x: $basefield, greatest: bool) -> Result<Self, GroupDecodingError> {
+                let p = Self::get_point_from_x(x, greatest).ok_or(GroupDecodingError::NotOnCurve)?;
+
+                if p.is_in_correct_subgroup_assuming_on_curve() {
+                    Ok(p)
+                } else {
+                    Err(GroupDecodingError::NotInSubgroup)
                 }
             }
 
-            #[inline(always)]
-            fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
-                self.mul($scalarfield::char()).is_zero()
+            /// Returns the x-coordinate, or zero for the point at infinity.
+            pub fn x(&self) -> $basefield {
+                if self.infinity {
+                    $basefield::zero()
+                } else {
+                    self.x
+                }
+            }
+
+            /// Returns the y-coordinate, or zero for the point at infinity.
+            pub fn y(&self) -> $basefield {
+                if self.infinity {
+                    $basefield::zero()
+                } else {
+                    self.y
+                }
+            }
+        }
+
+        #[cfg(test)]
+        impl crate::tests::curve::XyConstructors for $affine {
+            fn x(&self) -> $basefield {
+                $affine::x(self)
+            }
+
+            fn y(&self) -> $basefield {
+                $affine::y(self)
+            }
+
+            fn from_xy_checked(x: $basefield, y: $basefield) -> Result<Self, GroupDecodingError> {
+                $affine::from_xy_checked(x, y)
+            }
+
+            fn from_xy_unchecked(x: $basefield, y: $basefield) -> Self {
+                $affine::from_xy_unchecked(x, y)
+            }
+
+            fn from_x_and_sign(x: $basefield, greatest: bool) -> Result<Self, GroupDecodingError> {
+                $affine::from_x_and_sign(x, greatest)
             }
         }
 
@@ -197,6 +364,28 @@ macro_rules! curve_impl {
                 (*self).into()
             }
 
+            fn is_on_curve(&self) -> bool {
+                if self.is_zero() {
+                    true
+                } else {
+                    // Check that the point is on the curve
+                    let mut y2 = self.y;
+                    y2.square();
+
+                    let mut x3axb = self.x;
+                    x3axb.square(); // x^2
+                    x3axb.add_assign(&Self::get_coeff_a()); // x^2 + a
+                    x3axb.mul_assign(&self.x); // x^3 + ax
+                    x3axb.add_assign(&Self::get_coeff_b()); // x^3 + ax + b
+
+                    y2 == x3axb
+                }
+            }
+
+            fn is_in_subgroup(&self) -> bool {
+                self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve()
+            }
+
             #[inline(always)]
             fn prepare(&self) -> Self::Prepared {
                 $prepared::from_affine(*self)
@@ -262,57 +451,61 @@ macro_rules! curve_impl {
 
             #[inline(always)]
             fn batch_normalization(v: &mut [Self]) {
-                // Montgomery’s Trick and Fast Implementation of Masked AES
-                // Genelle, Prouff and Quisquater
-                // Section 3.2
-
-                // First pass: compute [a, ab, abc, ...]
-                let mut prod = Vec::with_capacity(v.len());
-                let mut tmp = $basefield::one();
-                for g in v
-                    .iter_mut()
-                    // Ignore normalized elements
-                    .filter(|g| !g.is_normalized())
-                {
-                    tmp.mul_assign(&g.z);
-                    prod.push(tmp);
-                }
+                // Chunks are normalized independently, on several threads with the
+                // `parallel` feature.
+                crate::parallel::for_each_chunk(v, 1024, |v| {
+                    // Montgomery’s Trick and Fast Implementation of Masked AES
+                    // Genelle, Prouff and Quisquater
+                    // Section 3.2
+
+                    // First pass: compute [a, ab, abc, ...]
+                    let mut prod = Vec::with_capacity(v.len());
+                    let mut tmp = $basefield::one();
+                    for g in v
+                        .iter_mut()
+                        // Ignore normalized elements
+                        .filter(|g| !g.is_normalized())
+                    {
+                        tmp.mul_assign(&g.z);
+                        prod.push(tmp);
+                    }
 
-                // Invert `tmp`.
-                tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-                // Second pass: iterate backwards to compute inverses
-                for (g, s) in v
-                    .iter_mut()
-                    // Backwards
-                    .rev()
-                    // Ignore normalized elements
-                    .filter(|g| !g.is_normalized())
-                    // Backwards, skip last element, fill in one for last term.
-                    .zip(
-                        prod.into_iter()
-                            .rev()
-                            .skip(1)
-                            .chain(Some($basefield::one())),
-                    )
-                {
-                    // tmp := tmp * g.z; g.z := tmp * s = 1/z
-                    let mut newtmp = tmp;
-                    newtmp.mul_assign(&g.z);
-                    g.z = tmp;
-                    g.z.mul_assign(&s);
-                    tmp = newtmp;
-                }
+                    // Invert `tmp`.
+                    tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
+
+                    // Second pass: iterate backwards to compute inverses
+                    for (g, s) in v
+                        .iter_mut()
+                        // Backwards
+                        .rev()
+                        // Ignore normalized elements
+                        .filter(|g| !g.is_normalized())
+                        // Backwards, skip last element, fill in one for last term.
+                        .zip(
+                            prod.into_iter()
+                                .rev()
+                                .skip(1)
+                                .chain(Some($basefield::one())),
+                        )
+                    {
+                        // tmp := tmp * g.z; g.z := tmp * s = 1/z
+                        let mut newtmp = tmp;
+                        newtmp.mul_assign(&g.z);
+                        g.z = tmp;
+                        g.z.mul_assign(&s);
+                        tmp = newtmp;
+                    }
 
-                // Perform affine transformations
-                for g in v.iter_mut().filter(|g| !g.is_normalized()) {
-                    let mut z = g.z; // 1/z
-                    z.square(); // 1/z^2
-                    g.x.mul_assign(&z); // x/z^2
-                    z.mul_assign(&g.z); // 1/z^3
-                    g.y.mul_assign(&z); // y/z^3
-                    g.z = $basefield::one(); // z = 1
-                }
+                    // Perform affine transformations
+                    for g in v.iter_mut().filter(|g| !g.is_normalized()) {
+                        let mut z = g.z; // 1/z
+                        z.square(); // 1/z^2
+                        g.x.mul_assign(&z); // x/z^2
+                        z.mul_assign(&g.z); // 1/z^3
+                        g.y.mul_assign(&z); // y/z^3
+                        g.z = $basefield::one(); // z = 1
+                    }
+                });
             }
 
             // https://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html#doubling-dbl-2007-bl
@@ -590,6 +783,10 @@ macro_rules! curve_impl {
                 (*self).into()
             }
 
+            fn clear_cofactor(&self) -> Self {
+                self.mul_by_effective_cofactor()
+            }
+
             #[inline(always)]
             fn recommended_wnaf_for_scalar(scalar: <Self::Scalar as PrimeField>::Repr) -> usize {
                 Self::empirical_recommended_wnaf_for_scalar(scalar)
@@ -601,6 +798,15 @@ macro_rules! curve_impl {
             }
         }
 
+        // Points are (de)serialized through their compressed encoding, which
+        // checks subgroup membership when decoding.
+        #[cfg(feature = "serde")]
+        serde_impl!(
+            $affine,
+            crate::serialization::serialize_point,
+            crate::serialization::deserialize_point
+        );
+
         // The affine point X, Y is represented in the jacobian
         // coordinates with Z = 1.
         impl From<$affine> for $projective {
@@ -664,6 +870,7 @@ pub mod g1 {
     use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
     use crate::{RawEncodable, CurveAffine, CurveProjective, EncodedPoint, GroupDecodingError, Engine};
     use rand::{Rand, Rng};
+    use crate::ct::{Choice, ConditionallySelectable, ConstantTimeEq, CtCurveAffine, CtCurveProjective, CtField};
     use std::fmt;
 
     curve_impl!(
@@ -910,6 +1117,11 @@ pub mod g1 {
             self.into_projective()
         }
 
+        /// G1 has cofactor one, so every point on the curve is in it.
+        fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+            true
+        }
+
         fn get_generator() -> Self {
             G1Affine {
                 x: super::super::fq::G1_GENERATOR_X,
@@ -985,6 +1197,11 @@ pub mod g1 {
     }
 
     impl G1 {
+        /// G1 has cofactor one, so every point on the curve is already in it.
+        fn mul_by_effective_cofactor(&self) -> G1 {
+            *self
+        }
+
         fn get_coeff_a() -> Fq {
             super::super::fq::A_COEFF
         }
@@ -1064,6 +1281,11 @@ pub mod g1 {
         crate::tests::curve::random_transformation_tests::<G1>();
     }
 
+    #[test]
+    fn g1_ct_tests() {
+        crate::tests::curve::ct_tests::<G1>();
+    }
+
     #[test]
     fn test_g1_addition_correctness() {
         let mut p = G1 {
@@ -1251,6 +1473,11 @@ pub mod g1 {
             }
         );
     }
+
+    #[test]
+    fn test_g1_xy_constructors() {
+        crate::tests::curve::xy_constructor_tests::<G1>();
+    }
 }
 
 pub mod g2 {
@@ -1259,6 +1486,7 @@ pub mod g2 {
     use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
     use crate::{CurveAffine, CurveProjective, EncodedPoint, GroupDecodingError, Engine};
     use rand::{Rand, Rng};
+    use crate::ct::{Choice, ConditionallySelectable, ConstantTimeEq, CtCurveAffine, CtCurveProjective, CtField};
     use std::fmt;
 
     curve_impl!(
@@ -1399,8 +1627,10 @@ pub mod g2 {
             } else {
                 let mut writer = &mut res.0[..];
 
+                affine.x.c2.into_repr().write_be(&mut writer).unwrap();
                 affine.x.c1.into_repr().write_be(&mut writer).unwrap();
                 affine.x.c0.into_repr().write_be(&mut writer).unwrap();
+                affine.y.c2.into_repr().write_be(&mut writer).unwrap();
                 affine.y.c1.into_repr().write_be(&mut writer).unwrap();
                 affine.y.c0.into_repr().write_be(&mut writer).unwrap();
             }
@@ -1517,6 +1747,7 @@ pub mod g2 {
                 {
                     let mut writer = &mut res.0[..];
 
+                    affine.x.c2.into_repr().write_be(&mut writer).unwrap();
                     affine.x.c1.into_repr().write_be(&mut writer).unwrap();
                     affine.x.c0.into_repr().write_be(&mut writer).unwrap();
                 }
@@ -1575,12 +1806,20 @@ pub mod g2 {
             projective
         }
 
+        fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+            self.mul(Fr::char()).is_zero()
+        }
+
         fn perform_pairing(&self, other: &G1Affine) -> Fq6 {
             super::super::Mnt6::pairing(*other, *self)
         }
     }
 
     impl G2 {
+        fn mul_by_effective_cofactor(&self) -> G2 {
+            self.into_affine().scale_by_cofactor()
+        }
+
         pub fn get_coeff_a() -> Fq3 {
             super::super::fq::G2_A_COEFF
         }
@@ -1621,6 +1860,19 @@ pub mod g2 {
         pub y_over_twist:          Fq3,
         pub double_coefficients:   Vec<AteDoubleCoefficients>,
         pub addition_coefficients: Vec<AteAdditionCoefficients>,
+        // lines of the G2PreparedAffine this was converted from, if any, in
+        // which case there are no double and addition coefficients
+        pub(crate) affine_coefficients: Vec<AteAffineCoefficients>,
+    }
+
+    /// A prepared element of G2 whose lines are computed in affine
+    /// coordinates, with two coefficients each instead of the four of a
+    /// doubling step in `G2Prepared`. It can be paired directly or converted
+    /// into a `G2Prepared` for `Engine::miller_loop`.
+    #[derive(Eq, PartialEq, Clone, Debug)]
+    pub struct G2PreparedAffine {
+        pub p:            G2Affine,
+        pub coefficients: Vec<AteAffineCoefficients>,
     }
 
     pub struct G2ProjectiveExtended {
@@ -1644,6 +1896,13 @@ pub mod g2 {
         pub c_rz: Fq3,
     }
 
+    /// The line `y = c_gamma * x - c_gamma_x_minus_y` on the twist.
+    #[derive(Eq, PartialEq, Copy, Clone, Debug)]
+    pub struct AteAffineCoefficients {
+        pub c_gamma:           Fq3,
+        pub c_gamma_x_minus_y: Fq3,
+    }
+
     #[cfg(test)]
     use rand::{SeedableRng, XorShiftRng};
 
@@ -1709,6 +1968,38 @@ pub mod g2 {
         crate::tests::curve::random_transformation_tests::<G2>();
     }
 
+    #[test]
+    fn g2_ct_tests() {
+        crate::tests::curve::ct_tests::<G2>();
+    }
+
+    #[test]
+    fn test_g2_encoding_layout() {
+        // Regression test: the encoders used to skip the c2 coordinates, so
+        // G2 points did not round-trip. Each coordinate is written as
+        // c2 || c1 || c0, with 96 big-endian bytes per element.
+        let p = G2Affine::one();
+
+        let mut expected = vec![];
+        for c in [p.x.c2, p.x.c1, p.x.c0, p.y.c2, p.y.c1, p.y.c0].iter() {
+            c.into_repr().write_be(&mut expected).unwrap();
+        }
+
+        let uncompressed = p.into_uncompressed();
+        assert_eq!(uncompressed.as_ref(), &expected[..]);
+        assert_eq!(uncompressed.into_affine().unwrap(), p);
+
+        let compressed = p.into_compressed();
+        let mut x = compressed.as_ref().to_vec();
+        x[0] &= 0x1f;
+        assert_eq!(&x[..], &expected[..288]);
+        assert_eq!(compressed.into_affine().unwrap(), p);
+    }
+
+    #[test]
+    fn test_g2_xy_constructors() {
+        crate::tests::curve::xy_constructor_tests::<G2>();
+    }
 }
 pub use self::g1::*;
 pub use self::g2::*;
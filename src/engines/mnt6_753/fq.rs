@@ -471,6 +471,11 @@ pub const FQ3_T_MINUS_1: [u64; 36] = [
 #[PrimeFieldGenerator = "17"]
 pub struct Fq(FqRepr);
 
+ct_prime_field_impl!(Fq, FqRepr);
+
+#[cfg(feature = "serde")]
+serde_impl!(Fq, crate::serialization::serialize_field, crate::serialization::deserialize_field);
+
 impl Fq {
     pub fn mul_by_nonresidue(&mut self) {
         self.mul_assign(&NON_RESIDUE)
@@ -33,3 +33,22 @@ fn fq3_field_tests() {
     crate::tests::field::random_sqrt_tests::<Fq3>();
     crate::tests::field::random_frobenius_tests::<Fq3, _>(super::fq::Fq::char(), 13);
 }
+
+#[test]
+fn test_fq3_sqrt() {
+    use crate::CurveAffine;
+    use ff::{Field, SqrtField};
+
+    // Regression test: the result of the initial exponentiation by
+    // (t - 1) / 2 used to be discarded, and sqrt did not terminate for the
+    // squares that come up when decompressing G2 points.
+    let y = super::G2Affine::one().y();
+    let mut y2 = y;
+    y2.square();
+
+    let mut root = y2.sqrt().unwrap();
+    if root != y {
+        root.negate();
+    }
+    assert_eq!(root, y);
+}
@@ -1,8 +1,8 @@
 extern crate std;
 
 use super::{
-    fq::{FROBENIUS_COEFF_FQ6_C1, Fq},
-    fq3::{ Fq3Extension },
+    fq::{FROBENIUS_COEFF_FQ6_C1, Fq, FqRepr},
+    fq3::{ Fq3, Fq3Extension },
 };
 
 use crate::{
@@ -12,8 +12,13 @@ use crate::{
             Fp6Extension,
         },
     },
+    gt::{read_fq, write_fq},
+    TargetField,
 };
 
+use ff::{Field, PrimeFieldDecodingError};
+use std::mem;
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Fq6Extension();
 
@@ -24,10 +29,59 @@ impl Fp6Extension for Fq6Extension {
 
 pub type Fq6 = Fp6<Fq6Extension>;
 
+const FQ_SIZE: usize = mem::size_of::<FqRepr>();
+
+impl TargetField for Fq6 {
+    type Subfield = Fq3;
+
+    const SUBFIELD_SIZE: usize = 3 * FQ_SIZE;
+
+    fn from_subfield(c0: Fq3, c1: Fq3) -> Self {
+        Fq6 { c0, c1 }
+    }
+
+    fn into_subfield(self) -> (Fq3, Fq3) {
+        (self.c0, self.c1)
+    }
+
+    fn mul_subfield_by_nonresidue(c: &mut Fq3) {
+        Fq6Extension::mul_by_nonresidue(c);
+    }
+
+    fn write_subfield(c: &Fq3, bytes: &mut [u8]) {
+        let coeffs = [c.c2, c.c1, c.c0];
+        for (coeff, chunk) in coeffs.iter().zip(bytes.chunks_mut(FQ_SIZE)) {
+            write_fq(coeff, chunk);
+        }
+    }
+
+    fn read_subfield(bytes: &[u8]) -> Result<Fq3, PrimeFieldDecodingError> {
+        let mut coeffs = [Fq::zero(); 3];
+        for (coeff, chunk) in coeffs.iter_mut().zip(bytes.chunks(FQ_SIZE)) {
+            *coeff = read_fq(chunk)?;
+        }
+
+        Ok(Fq3 { c0: coeffs[2], c1: coeffs[1], c2: coeffs[0] })
+    }
+
+    fn conjugate(&mut self) {
+        Fq6::conjugate(self);
+    }
+
+    fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        Fq6::cyclotomic_exp(self, exp)
+    }
+}
+
 #[test]
 fn fq6_field_tests() {
     use ff::PrimeField;
 
     crate::tests::field::random_field_tests::<Fq6>();
     crate::tests::field::random_frobenius_tests::<Fq6, _>(super::fq::Fq::char(), 13);
-}
\ No newline at end of file
+}
+
+#[test]
+fn fq6_torus_tests() {
+    crate::tests::torus::t2_tests::<Fq6>();
+}
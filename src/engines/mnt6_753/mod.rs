@@ -5,13 +5,13 @@ mod fq6;
 mod fr;
 
 use self::{
-    ec::g2::{ AteDoubleCoefficients, AteAdditionCoefficients, G2ProjectiveExtended },
+    ec::g2::{ AteDoubleCoefficients, AteAdditionCoefficients, AteAffineCoefficients, G2ProjectiveExtended },
     fq::{ MNT6_X, MNT6_X_IS_NEGATIVE, TWIST_INV, TWIST, EXP_W0, EXP_W1, EXP_W0_IS_NEGATIVE }
 };
 
 pub use self::ec::{
     G1Affine, G1Compressed, G1Prepared, G1Uncompressed, G2Affine, G2Compressed, G2Prepared,
-    G2Uncompressed, G1, G2,
+    G2PreparedAffine, G2Uncompressed, G1, G2,
 };
 
 pub use self::fq::{Fq, FqRepr};
@@ -19,102 +19,131 @@ pub use self::fq3::Fq3;
 pub use self::fq6::Fq6;
 pub use self::fr::{Fr, FrRepr};
 
-use crate::{Engine, CurveAffine};
+use crate::{generics::fields::batch_inversion, parallel, wnaf::naf_form, Engine, CurveAffine, Gt};
+use crate::prepared::{
+    mismatched_coefficients, read_fq, read_header, read_len, read_point, read_version, write_fq,
+    write_header, write_len, write_point, write_version, AFFINE_LINES, PROJECTIVE_LINES,
+};
 
-use ff::{BitIterator, Field, ScalarEngine};
+use ff::{Field, ScalarEngine};
+use std::io::{self, Read, Write};
+use std::slice;
+use std::sync::OnceLock;
 
 #[derive(Clone, Debug)]
 pub struct Mnt6;
 
 impl Mnt6 {
 
-    fn ate_pairing_loop(
-        p: &G1Prepared,
-        q: &G2Prepared,
-    ) -> Fq6 {
-
-        let mut l1_coeff = Fq3::zero();
-        l1_coeff.c0 = p.p.x;
-        l1_coeff.sub_assign(&q.x_over_twist);
-
-        let mut f = Fq6::one();
+    /// Evaluates at `p` the line through the point doubled in a doubling step.
+    fn doubling_line(p: &G1Prepared, dc: &AteDoubleCoefficients) -> Fq6 {
+        let mut g_rr_at_p = Fq6::zero();
 
-        let mut dbl_idx: usize = 0;
-        let mut add_idx: usize = 0;
+        let mut t0 = dc.c_j;
+        t0.mul_assign(&p.x_by_twist);
+        t0.negate();
+        t0.add_assign(&dc.c_l);
+        t0.sub_assign(&dc.c_4c);
 
+        let mut t1 = dc.c_h;
+        t1.mul_assign(&p.y_by_twist);
 
-        // The for loop is executed for all bits (EXCEPT the MSB itself) of
-        let mut found_one = false;
-        for bit in BitIterator::new(&MNT6_X).skip(1) {
-            if !found_one {
-                found_one = bit;
-                continue;
-            }
-            
-            let dc = &q.double_coefficients[dbl_idx];
-            dbl_idx += 1;
+        g_rr_at_p.c0 = t0;
+        g_rr_at_p.c1 = t1;
 
-            let mut g_rr_at_p = Fq6::zero();
+        g_rr_at_p
+    }
 
-            let mut t0 = dc.c_j;
-            t0.mul_assign(&p.x_by_twist);
-            t0.negate();
-            t0.add_assign(&dc.c_l);
-            t0.sub_assign(&dc.c_4c);
+    /// Evaluates at `p` the line through the points added in an addition
+    /// step, where `l1_coeff` is `p.x - q.x / twist` and `y_over_twist` is
+    /// `q.y / twist`.
+    fn addition_line(
+        p: &G1Prepared,
+        l1_coeff: &Fq3,
+        y_over_twist: &Fq3,
+        ac: &AteAdditionCoefficients,
+    ) -> Fq6 {
+        let mut g_rq_at_p = Fq6::zero();
 
-            let mut t1 = dc.c_h;
-            t1.mul_assign(&p.y_by_twist);
+        let mut t0 = ac.c_rz;
+        t0.mul_assign(&p.y_by_twist);
 
-            g_rr_at_p.c0 = t0;
-            g_rr_at_p.c1 = t1;
+        let mut t = *l1_coeff;
+        t.mul_assign(&ac.c_l1);
 
-            f.square();
-            f.mul_assign(&g_rr_at_p);
+        let mut t1 = *y_over_twist;
+        t1.mul_assign(&ac.c_rz);
+        t1.add_assign(&t);
+        t1.negate();
 
-            if bit {
-                let ac = &q.addition_coefficients[add_idx];
-                add_idx += 1;
+        g_rq_at_p.c0 = t0;
+        g_rq_at_p.c1 = t1;
 
-                let mut g_rq_at_p = Fq6::zero();
+        g_rq_at_p
+    }
 
-                let mut t0 = ac.c_rz;
-                t0.mul_assign(&p.y_by_twist);
+    /// Evaluates at `p` a line computed in affine coordinates, scaled like
+    /// the lines of doubling steps.
+    fn affine_line(p: &G1Prepared, c: &AteAffineCoefficients) -> Fq6 {
+        let mut g_at_p = Fq6::zero();
 
-                let mut t = l1_coeff;
-                t.mul_assign(&ac.c_l1);
+        let mut t0 = c.c_gamma;
+        t0.mul_assign(&p.x_by_twist);
+        t0.negate();
+        t0.add_assign(&c.c_gamma_x_minus_y);
 
-                let mut t1 = q.y_over_twist;
-                t1.mul_assign(&ac.c_rz);
-                t1.add_assign(&t);
-                t1.negate();
+        g_at_p.c0 = t0;
+        g_at_p.c1 = p.y_by_twist;
 
-                g_rq_at_p.c0 = t0;
-                g_rq_at_p.c1 = t1;
+        g_at_p
+    }
 
-                f.mul_assign(&g_rq_at_p);
+    /// Performs a Miller loop over `G2PreparedAffine` elements, which is
+    /// what `Engine::miller_loop` does for `G2Prepared` elements converted
+    /// from them.
+    pub fn miller_loop_affine<'a, I>(i: I) -> Fq6
+    where
+        I: IntoIterator<Item = &'a (&'a G1Prepared, &'a G2PreparedAffine)>,
+    {
+        let mut pairs = vec![];
+        for &(p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs.push((p, Lines::Affine(q.coefficients.iter())));
             }
         }
 
-        if MNT6_X_IS_NEGATIVE {
-            let ac = &q.addition_coefficients[add_idx];
+        parallel::product(pairs, 4, Self::miller_loop_lines)
+    }
 
-            let mut g_rnegr_at_p = Fq6::zero();
+    fn miller_loop_lines(mut pairs: Vec<(&G1Prepared, Lines)>) -> Fq6 {
+        // All pairs share the accumulator, so each bit costs a single squaring.
+        let mut f = Fq6::one();
 
-            let mut t0 = ac.c_rz;
-            t0.mul_assign(&p.y_by_twist);
+        // The loop runs over the NAF digits of the loop count below the
+        // leading one, adding -q for the negative ones.
+        for &digit in naf_form(&MNT6_X).iter().rev().skip(1) {
+            f.square();
 
-            let mut t = l1_coeff;
-            t.mul_assign(&ac.c_l1);
+            for &mut (p, ref mut lines) in &mut pairs {
+                f.mul_assign(&lines.doubling_line(p));
+            }
 
-            let mut t1 = q.y_over_twist;
-            t1.mul_assign(&ac.c_rz);
-            t1.add_assign(&t);
-            t1.negate();
+            if digit != 0 {
+                for &mut (p, ref mut lines) in &mut pairs {
+                    f.mul_assign(&lines.addition_line(p, digit < 0));
+                }
+            }
+        }
 
-            g_rnegr_at_p.c0 = t0;
-            g_rnegr_at_p.c1 = t1;
+        if MNT6_X_IS_NEGATIVE {
+            for &mut (p, ref mut lines) in &mut pairs {
+                // Adding -r gives a vertical line, which the final
+                // exponentiation kills, so the affine lines leave it out.
+                if let Lines::Projective { .. } = *lines {
+                    f.mul_assign(&lines.addition_line(p, false));
+                }
+            }
 
-            f.mul_assign(&g_rnegr_at_p);
             f = f.inverse().expect("It should not throw");
         }
 
@@ -139,15 +168,16 @@ impl Mnt6 {
         alpha
     }
 
-    fn final_exponentiation_part_two(elt: &Fq6, &elt_inv: &Fq6) -> Fq6 {
-        let mut elt_q = elt.clone();
+    fn final_exponentiation_part_two(elt: &Fq6) -> Fq6 {
+        let mut elt_q = *elt;
         elt_q.frobenius_map(1);
 
+        // elt is in the cyclotomic subgroup, where the inverse is the conjugate
         let mut w1_part = elt_q.cyclotomic_exp(&EXP_W1);
-        let w0_part = match EXP_W0_IS_NEGATIVE {
-            true => elt_inv.cyclotomic_exp(&EXP_W0),
-            false => elt.cyclotomic_exp(&EXP_W0),
-        };
+        let mut w0_part = elt.cyclotomic_exp(&EXP_W0);
+        if EXP_W0_IS_NEGATIVE {
+            w0_part.conjugate();
+        }
 
         w1_part.mul_assign(&w0_part);
         w1_part
@@ -167,6 +197,7 @@ impl Engine for Mnt6 {
     type Fq = Fq;
     type Fqe = Fq3;
     type Fqk = Fq6;
+    type Gt = Gt<Self>;
 
     fn miller_loop<'a, I>(i: I) -> Self::Fqk
     where
@@ -177,14 +208,14 @@ impl Engine for Mnt6 {
             ),
         >,
     {
-        let mut f = Fq6::one();
-        for (p, q) in i.into_iter() {
+        let mut pairs = vec![];
+        for &(p, q) in i {
             if !p.is_zero() && !q.is_zero() {
-                f.mul_assign(&Self::ate_pairing_loop(p, q));
+                pairs.push((p, Lines::new(p, q)));
             }
         }
 
-        f
+        parallel::product(pairs, 4, Self::miller_loop_lines)
     }
 
     fn final_exponentiation(f: &Fq6) -> Option<Fq6> {
@@ -194,9 +225,67 @@ impl Engine for Mnt6 {
         }
         let value_inv = value_inv.expect("is some");
         let value_to_first_chunk = Self::final_exponentiation_part_one(f, &value_inv);
-        let value_inv_to_first_chunk = Self::final_exponentiation_part_one(&value_inv, f);
 
-        Some(Self::final_exponentiation_part_two(&value_to_first_chunk, &value_inv_to_first_chunk))
+        Some(Self::final_exponentiation_part_two(&value_to_first_chunk))
+    }
+
+    fn gt_generator() -> Fq6 {
+        static GENERATOR: OnceLock<Fq6> = OnceLock::new();
+
+        *GENERATOR.get_or_init(|| Self::pairing(G1Affine::one(), G2Affine::one()))
+    }
+}
+
+/// The remaining lines of a prepared element of G2, in either form.
+#[allow(clippy::large_enum_variant)]
+enum Lines<'a> {
+    Projective {
+        l1_coeff: Fq3,
+        y_over_twist: Fq3,
+        double: slice::Iter<'a, AteDoubleCoefficients>,
+        addition: slice::Iter<'a, AteAdditionCoefficients>,
+    },
+    Affine(slice::Iter<'a, AteAffineCoefficients>),
+}
+
+impl<'a> Lines<'a> {
+    fn new(p: &G1Prepared, q: &'a G2Prepared) -> Self {
+        if !q.affine_coefficients.is_empty() {
+            return Lines::Affine(q.affine_coefficients.iter());
+        }
+
+        let mut l1_coeff = Fq3::zero();
+        l1_coeff.c0 = p.p.x;
+        l1_coeff.sub_assign(&q.x_over_twist);
+
+        Lines::Projective {
+            l1_coeff,
+            y_over_twist: q.y_over_twist,
+            double: q.double_coefficients.iter(),
+            addition: q.addition_coefficients.iter(),
+        }
+    }
+
+    fn doubling_line(&mut self, p: &G1Prepared) -> Fq6 {
+        match *self {
+            Lines::Projective { ref mut double, .. } => Mnt6::doubling_line(p, double.next().unwrap()),
+            Lines::Affine(ref mut coeffs) => Mnt6::affine_line(p, coeffs.next().unwrap()),
+        }
+    }
+
+    /// Returns the line of the next addition step, which adds `-q` if
+    /// `negative` is set.
+    fn addition_line(&mut self, p: &G1Prepared, negative: bool) -> Fq6 {
+        match *self {
+            Lines::Projective { ref l1_coeff, mut y_over_twist, ref mut addition, .. } => {
+                if negative {
+                    y_over_twist.negate();
+                }
+
+                Mnt6::addition_line(p, l1_coeff, &y_over_twist, addition.next().unwrap())
+            }
+            Lines::Affine(ref mut coeffs) => Mnt6::affine_line(p, coeffs.next().unwrap()),
+        }
     }
 }
 
@@ -212,6 +301,7 @@ impl G2Prepared {
             y_over_twist: Fq3::zero(),
             double_coefficients:   vec![],
             addition_coefficients: vec![],
+            affine_coefficients:   vec![],
         };
         res.precompute();
         res
@@ -397,19 +487,17 @@ impl G2Prepared {
             t: Fq3::one(),
         };
 
-        let mut found_one = false;
-        for bit in BitIterator::new(&MNT6_X).skip(1) {
-
-            if !found_one {
-                found_one = bit;
-                continue;
-            }
+        let mut minus_y = self.p.y;
+        minus_y.negate();
 
+        // same walk over the NAF digits of the loop count as the Miller loop
+        for &digit in naf_form(&MNT6_X).iter().rev().skip(1) {
             let coeff = Self::doubling_step(&mut r);
             self.double_coefficients.push(coeff);
 
-            if bit {
-                let coeff = Self::addition_step(&self.p.x, &self.p.y, &mut r);
+            if digit != 0 {
+                let y = if digit > 0 { &self.p.y } else { &minus_y };
+                let coeff = Self::addition_step(&self.p.x, y, &mut r);
                 self.addition_coefficients.push(coeff);
             }
         }
@@ -438,6 +526,93 @@ impl G2Prepared {
     }
 }
 
+impl G2PreparedAffine {
+    pub fn is_zero(&self) -> bool {
+        self.p.infinity
+    }
+
+    pub fn from_affine(q: G2Affine) -> Self {
+        let prepared = G2Prepared::from_affine(q);
+        if prepared.is_zero() {
+            return G2PreparedAffine {
+                p: q,
+                coefficients: vec![],
+            };
+        }
+
+        let mut minus_y = q.y;
+        minus_y.negate();
+
+        // The slope gamma and gamma * x - y of every line, as fractions of
+        // the projective coefficients with a common denominator
+        let mut numerators = vec![];
+        let mut denominators = vec![];
+
+        let mut dbl_coeffs = prepared.double_coefficients.iter();
+        let mut add_coeffs = prepared.addition_coefficients.iter();
+        for &digit in naf_form(&MNT6_X).iter().rev().skip(1) {
+            let dc = dbl_coeffs.next().unwrap();
+            let mut c_gamma_x_minus_y = dc.c_l;
+            c_gamma_x_minus_y.sub_assign(&dc.c_4c);
+
+            numerators.push((dc.c_j, c_gamma_x_minus_y));
+            denominators.push(dc.c_h);
+
+            if digit != 0 {
+                // the line goes through the added point (q.x, y)
+                let ac = add_coeffs.next().unwrap();
+                let y = if digit > 0 { &q.y } else { &minus_y };
+
+                let mut c_gamma_x_minus_y = ac.c_l1;
+                c_gamma_x_minus_y.mul_assign(&q.x);
+                let mut t = ac.c_rz;
+                t.mul_assign(y);
+                c_gamma_x_minus_y.sub_assign(&t);
+
+                numerators.push((ac.c_l1, c_gamma_x_minus_y));
+                denominators.push(ac.c_rz);
+            }
+        }
+
+        batch_inversion(&mut denominators);
+
+        let coefficients = numerators
+            .into_iter()
+            .zip(denominators.iter())
+            .map(|((mut c_gamma, mut c_gamma_x_minus_y), den_inv)| {
+                c_gamma.mul_assign(den_inv);
+                c_gamma_x_minus_y.mul_assign(den_inv);
+
+                AteAffineCoefficients { c_gamma, c_gamma_x_minus_y }
+            })
+            .collect();
+
+        G2PreparedAffine { p: q, coefficients }
+    }
+}
+
+impl From<G2PreparedAffine> for G2Prepared {
+    fn from(q: G2PreparedAffine) -> Self {
+        let mut res = G2Prepared {
+            p: q.p,
+            x_over_twist: Fq3::zero(),
+            y_over_twist: Fq3::zero(),
+            double_coefficients:   vec![],
+            addition_coefficients: vec![],
+            affine_coefficients:   q.coefficients,
+        };
+
+        if !res.p.is_zero() {
+            res.x_over_twist = res.p.x;
+            res.x_over_twist.mul_assign(&TWIST_INV);
+            res.y_over_twist = res.p.y;
+            res.y_over_twist.mul_assign(&TWIST_INV);
+        }
+
+        res
+    }
+}
+
 impl G1Prepared {
 
     pub fn is_zero(&self) -> bool {
@@ -462,7 +637,230 @@ impl G1Prepared {
     }
 }
 
+impl G2Prepared {
+    /// Writes a versioned encoding of this element, which `read` accepts.
+    /// The point is stored along with the lines, but not its images under
+    /// the twist, which are recomputed on load.
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        if self.affine_coefficients.is_empty() {
+            write_header(&mut writer, PROJECTIVE_LINES)?;
+            write_point(&mut writer, &self.p)?;
+            write_len(&mut writer, self.double_coefficients.len())?;
+            for dc in &self.double_coefficients {
+                write_fq3(&mut writer, &dc.c_h)?;
+                write_fq3(&mut writer, &dc.c_4c)?;
+                write_fq3(&mut writer, &dc.c_j)?;
+                write_fq3(&mut writer, &dc.c_l)?;
+            }
+            write_len(&mut writer, self.addition_coefficients.len())?;
+            for ac in &self.addition_coefficients {
+                write_fq3(&mut writer, &ac.c_l1)?;
+                write_fq3(&mut writer, &ac.c_rz)?;
+            }
+        } else {
+            write_header(&mut writer, AFFINE_LINES)?;
+            write_point(&mut writer, &self.p)?;
+            write_len(&mut writer, self.affine_coefficients.len())?;
+            for c in &self.affine_coefficients {
+                write_fq3(&mut writer, &c.c_gamma)?;
+                write_fq3(&mut writer, &c.c_gamma_x_minus_y)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads an element written by `write`. The point must be in G2 and the
+    /// lines must be the ones computed when preparing it, which is checked by
+    /// preparing it again.
+    pub fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        let form = read_header(&mut reader)?;
+        let p: G2Affine = read_point(&mut reader)?;
+
+        let (doubles, additions, affine) = if p.is_zero() { (0, 0, 0) } else { prepared_line_counts() };
+
+        let mut res = G2Prepared::from(G2PreparedAffine { p, coefficients: vec![] });
+
+        if form == PROJECTIVE_LINES {
+            read_len(&mut reader, doubles)?;
+            for _ in 0..doubles {
+                res.double_coefficients.push(AteDoubleCoefficients {
+                    c_h: read_fq3(&mut reader)?,
+                    c_4c: read_fq3(&mut reader)?,
+                    c_j: read_fq3(&mut reader)?,
+                    c_l: read_fq3(&mut reader)?,
+                });
+            }
+
+            read_len(&mut reader, additions)?;
+            for _ in 0..additions {
+                res.addition_coefficients.push(AteAdditionCoefficients {
+                    c_l1: read_fq3(&mut reader)?,
+                    c_rz: read_fq3(&mut reader)?,
+                });
+            }
+        } else {
+            read_len(&mut reader, affine)?;
+            for _ in 0..affine {
+                res.affine_coefficients.push(AteAffineCoefficients {
+                    c_gamma: read_fq3(&mut reader)?,
+                    c_gamma_x_minus_y: read_fq3(&mut reader)?,
+                });
+            }
+        }
+
+        let expected = if form == PROJECTIVE_LINES {
+            p.prepare()
+        } else {
+            G2Prepared::from(G2PreparedAffine::from_affine(p))
+        };
+        if res != expected {
+            return Err(mismatched_coefficients());
+        }
+
+        Ok(res)
+    }
+}
+
+impl G1Prepared {
+    /// Writes a versioned encoding of this element, which `read` accepts.
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write_version(&mut writer)?;
+        write_point(&mut writer, &self.p)?;
+        write_fq3(&mut writer, &self.x_by_twist)?;
+        write_fq3(&mut writer, &self.y_by_twist)
+    }
+
+    /// Reads an element written by `write`, checking that the point is in G1
+    /// and that the coefficients are its images under the twist.
+    pub fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        read_version(&mut reader)?;
+        let p: G1Affine = read_point(&mut reader)?;
+        let x_by_twist = read_fq3(&mut reader)?;
+        let y_by_twist = read_fq3(&mut reader)?;
+
+        let res = G1Prepared::from_affine(p);
+        if res.x_by_twist != x_by_twist || res.y_by_twist != y_by_twist {
+            return Err(mismatched_coefficients());
+        }
+
+        Ok(res)
+    }
+}
+
+/// Returns the number of doubling and addition coefficients, and of affine
+/// lines, of a prepared element of G2 other than zero.
+fn prepared_line_counts() -> (usize, usize, usize) {
+    let naf = naf_form(&MNT6_X);
+    let doubles = naf.len() - 1;
+    let additions = naf[..doubles].iter().filter(|&&digit| digit != 0).count();
+
+    (doubles, additions + MNT6_X_IS_NEGATIVE as usize, doubles + additions)
+}
+
+fn write_fq3<W: Write>(writer: &mut W, c: &Fq3) -> io::Result<()> {
+    write_fq(writer, &c.c2)?;
+    write_fq(writer, &c.c1)?;
+    write_fq(writer, &c.c0)
+}
+
+fn read_fq3<R: Read>(reader: &mut R) -> io::Result<Fq3> {
+    let c2 = read_fq(reader)?;
+    let c1 = read_fq(reader)?;
+    let c0 = read_fq(reader)?;
+
+    Ok(Fq3 { c0, c1, c2 })
+}
+
+#[cfg(test)]
+impl crate::tests::engine::AffineMillerLoop for Mnt6 {
+    type G2PreparedAffine = G2PreparedAffine;
+
+    fn prepare_affine(q: G2Affine) -> G2PreparedAffine {
+        G2PreparedAffine::from_affine(q)
+    }
+
+    fn is_zero(q: &G2PreparedAffine) -> bool {
+        q.is_zero()
+    }
+
+    fn into_prepared(q: G2PreparedAffine) -> G2Prepared {
+        G2Prepared::from(q)
+    }
+
+    fn miller_loop_affine(i: &[(&G1Prepared, &G2PreparedAffine)]) -> Fq6 {
+        Mnt6::miller_loop_affine(i)
+    }
+}
+
+#[test]
+fn test_g2_prepared_affine() {
+    crate::tests::engine::affine_miller_loop_tests::<Mnt6>();
+}
+
+#[test]
+fn test_prepared_encoding() {
+    use crate::{CurveProjective, EncodedPoint};
+    use rand::{Rand, SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    let q = G2::rand(&mut rng).into_affine();
+    let other = G2::rand(&mut rng).into_affine();
+
+    for prepared in vec![
+        q.prepare(),
+        G2Prepared::from(G2PreparedAffine::from_affine(q)),
+        G2Affine::zero().prepare(),
+    ] {
+        let mut encoded = vec![];
+        prepared.write(&mut encoded).unwrap();
+        assert_eq!(G2Prepared::read(&encoded[..]).unwrap(), prepared);
+
+        // an unknown version
+        let mut bad = encoded.clone();
+        bad[0] = 2;
+        assert!(G2Prepared::read(&bad[..]).is_err());
+
+        // truncated
+        assert!(G2Prepared::read(&encoded[..encoded.len() - 1]).is_err());
+
+        // the lines of one point next to another
+        let mut bad = encoded.clone();
+        bad[2..2 + G2Uncompressed::size()].copy_from_slice(other.into_uncompressed().as_ref());
+        assert!(G2Prepared::read(&bad[..]).is_err());
+
+        // a corrupted last line
+        let mut bad = encoded.clone();
+        *bad.last_mut().unwrap() ^= 1;
+        assert!(G2Prepared::read(&bad[..]).is_err());
+    }
+
+    let p = G1::rand(&mut rng).into_affine();
+    let other = G1::rand(&mut rng).into_affine();
+
+    for prepared in vec![p.prepare(), G1Affine::zero().prepare()] {
+        let mut encoded = vec![];
+        prepared.write(&mut encoded).unwrap();
+        assert_eq!(G1Prepared::read(&encoded[..]).unwrap(), prepared);
+
+        let mut bad = encoded.clone();
+        bad[0] = 2;
+        assert!(G1Prepared::read(&bad[..]).is_err());
+
+        let mut bad = encoded.clone();
+        bad[1..1 + G1Uncompressed::size()].copy_from_slice(other.into_uncompressed().as_ref());
+        assert!(G1Prepared::read(&bad[..]).is_err());
+    }
+}
+
 #[test]
 fn mnt6_engine_tests() {
     crate::tests::engine::engine_tests::<Mnt6>();
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn mnt6_serde_tests() {
+    crate::tests::serialization::serde_tests::<Mnt6>();
+}
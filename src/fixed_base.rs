@@ -0,0 +1,170 @@
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::io::{self, Read, Write};
+
+use super::multiexp::get_bits;
+use super::{CurveAffine, CurveProjective, EncodedPoint, PrimeField};
+
+/// The largest supported window size.
+const MAX_WINDOW: usize = 22;
+
+/// Precomputed multiples of a fixed base, for repeated scalar multiplication
+/// of the same point.
+///
+/// The scalar is split into windows of `window` bits, and the table holds
+/// `j * 2^(window * i) * base` for every window `i` and every nonzero digit
+/// `j`, in affine form. A multiplication then costs one mixed addition per
+/// nonzero digit and no doublings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedBaseTable<G: CurveProjective> {
+    window: usize,
+    table: Vec<G::Affine>,
+}
+
+impl<G: CurveProjective> FixedBaseTable<G> {
+    /// Precomputes the table for `base` with the given window size. Larger
+    /// windows make `mul` faster, but the table grows as
+    /// `(2^window - 1) * ceil(NUM_BITS / window)` points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is not between 1 and 22, inclusive.
+    pub fn new(base: G, window: usize) -> Self {
+        assert!(
+            (1..=MAX_WINDOW).contains(&window),
+            "window size must be between 1 and {}",
+            MAX_WINDOW
+        );
+
+        let digits = (1 << window) - 1;
+        let mut table = Vec::with_capacity(num_windows::<G>(window) * digits);
+
+        let mut window_base = base;
+        for _ in 0..num_windows::<G>(window) {
+            let mut acc = window_base;
+            for _ in 0..digits {
+                table.push(acc);
+                acc.add_assign(&window_base);
+            }
+
+            // acc = 2^window * window_base
+            window_base = acc;
+        }
+
+        G::batch_normalization(&mut table);
+
+        FixedBaseTable {
+            window,
+            table: table.iter().map(|p| p.into_affine()).collect(),
+        }
+    }
+
+    /// Returns the window size of this table.
+    pub fn window_size(&self) -> usize {
+        self.window
+    }
+
+    /// Multiplies the base by `scalar`.
+    pub fn mul<S: Into<<G::Scalar as PrimeField>::Repr>>(&self, scalar: S) -> G {
+        let scalar = scalar.into();
+        let digits = (1 << self.window) - 1;
+
+        let mut res = G::zero();
+        for (i, multiples) in self.table.chunks(digits).enumerate() {
+            let digit = get_bits(&scalar, i * self.window, self.window);
+            if digit != 0 {
+                res.add_assign_mixed(&multiples[digit - 1]);
+            }
+        }
+
+        res
+    }
+
+    /// Serializes the table: the window size as a big-endian `u32`, followed
+    /// by every point in uncompressed form.
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_u32::<BigEndian>(self.window as u32)?;
+
+        for p in self.table.iter() {
+            writer.write_all(p.into_uncompressed().as_ref())?;
+        }
+
+        Ok(())
+    }
+
+    /// Deserializes a table written by `write`, checking that the base is a
+    /// valid element of the group and that every other point is the
+    /// expected multiple of it.
+    pub fn read<R: Read>(reader: R) -> io::Result<Self> {
+        Self::read_internal(reader, true)
+    }
+
+    /// Deserializes a table written by `write`, without checking the points.
+    /// This is much faster than `read`, but must only be used on trusted
+    /// input: an invalid table silently produces wrong results.
+    pub fn read_unchecked<R: Read>(reader: R) -> io::Result<Self> {
+        Self::read_internal(reader, false)
+    }
+
+    fn read_internal<R: Read>(mut reader: R, checked: bool) -> io::Result<Self> {
+        let window = reader.read_u32::<BigEndian>()? as usize;
+        if !(1..=MAX_WINDOW).contains(&window) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid window size",
+            ));
+        }
+
+        // The length is derived from untrusted input, so the table grows as
+        // points are actually read instead of being allocated up front.
+        let len = num_windows::<G>(window) * ((1 << window) - 1);
+        let mut table = Vec::new();
+        let mut encoded = <G::Affine as CurveAffine>::Uncompressed::empty();
+
+        for i in 0..len {
+            reader.read_exact(encoded.as_mut())?;
+
+            let p = if checked && i == 0 {
+                encoded.into_affine()
+            } else {
+                encoded.into_affine_unchecked()
+            };
+            table.push(p.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?);
+        }
+
+        let table = FixedBaseTable { window, table };
+        if checked && !table.is_well_formed() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "table does not match its base",
+            ));
+        }
+
+        Ok(table)
+    }
+
+    /// Checks that the table holds `j * 2^(window * i) * base`, where `base`
+    /// is the first point, by recomputing each entry from the previous one.
+    fn is_well_formed(&self) -> bool {
+        let digits = (1 << self.window) - 1;
+
+        let mut window_base = self.table[0].into_projective();
+        for multiples in self.table.chunks(digits) {
+            let mut acc = window_base;
+            for p in multiples {
+                if acc != p.into_projective() {
+                    return false;
+                }
+                acc.add_assign(&window_base);
+            }
+
+            // acc = 2^window * window_base
+            window_base = acc;
+        }
+
+        true
+    }
+}
+
+fn num_windows<G: CurveProjective>(window: usize) -> usize {
+    (G::Scalar::NUM_BITS as usize).div_ceil(window)
+}
@@ -5,9 +5,12 @@ use ff::{
         QuadraticNonResidue
     },
     Field, 
+    PrimeField,
     SqrtField,
 };
 
+use crate::ct::{fermat_inverse, Choice, ConditionallySelectable, ConstantTimeEq, CtField, CtOption};
+
 use rand::{Rand, Rng};
 use std::{
     cmp::Ordering,
@@ -29,6 +32,7 @@ pub trait Fp2Extension: 'static + Copy + Debug + Eq {
 
 /// An element of Fp2, represented by c0 + c1 * u.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fp2<P: Fp2Extension> {
     pub c0: P::Fp,
     pub c1: P::Fp,
@@ -197,6 +201,28 @@ impl<P: Fp2Extension> Field for Fp2<P> {
     }
 }
 
+impl<P: Fp2Extension> ConditionallySelectable for Fp2<P> where P::Fp: ConditionallySelectable {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fp2 {
+            c0: P::Fp::conditional_select(&a.c0, &b.c0, choice),
+            c1: P::Fp::conditional_select(&a.c1, &b.c1, choice),
+        }
+    }
+}
+
+impl<P: Fp2Extension> ConstantTimeEq for Fp2<P> where P::Fp: ConstantTimeEq {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+impl<P: Fp2Extension> CtField for Fp2<P> where P::Fp: CtField + PrimeField {
+    fn ct_inverse(&self) -> CtOption<Self> {
+        let inverse = fermat_inverse(self, P::Fp::char().as_ref(), 2);
+        CtOption::new(inverse, !self.ct_eq(&Self::zero()))
+    }
+}
+
 impl<P: Fp2Extension> SqrtField for Fp2<P> where P::Fp: SqrtField {
 
     #[inline(always)]
@@ -5,9 +5,12 @@ use ff::{
         QuadraticNonResidue
     },
     Field, 
+    PrimeField,
     SqrtField,
 };
 
+use crate::ct::{fermat_inverse, Choice, ConditionallySelectable, ConstantTimeEq, CtField, CtOption};
+
 use rand::{Rand, Rng};
 use std::{
     cmp::Ordering,
@@ -32,6 +35,7 @@ pub trait Fp3Extension: 'static + Copy + Debug + Eq {
 
 /// An element of Fp3, represented by c0 + c1 * v + c2 * v^(2).
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fp3<P: Fp3Extension> {
     pub c0: P::Fp,
     pub c1: P::Fp,
@@ -313,6 +317,29 @@ impl<P: Fp3Extension> Field for Fp3<P> {
     }
 }
 
+impl<P: Fp3Extension> ConditionallySelectable for Fp3<P> where P::Fp: ConditionallySelectable {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fp3 {
+            c0: P::Fp::conditional_select(&a.c0, &b.c0, choice),
+            c1: P::Fp::conditional_select(&a.c1, &b.c1, choice),
+            c2: P::Fp::conditional_select(&a.c2, &b.c2, choice),
+        }
+    }
+}
+
+impl<P: Fp3Extension> ConstantTimeEq for Fp3<P> where P::Fp: ConstantTimeEq {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+    }
+}
+
+impl<P: Fp3Extension> CtField for Fp3<P> where P::Fp: CtField + PrimeField {
+    fn ct_inverse(&self) -> CtOption<Self> {
+        let inverse = fermat_inverse(self, P::Fp::char().as_ref(), 3);
+        CtOption::new(inverse, !self.ct_eq(&Self::zero()))
+    }
+}
+
 impl<P: Fp3Extension> SqrtField for Fp3<P> where P::Fp: SqrtField {
     
     #[inline(always)]
@@ -335,8 +362,7 @@ impl<P: Fp3Extension> SqrtField for Fp3<P> where P::Fp: SqrtField {
                     c2: P::QUADRATIC_NONRESIDUE_TO_T.2,
                 };
                 // Fp3_model<n,modulus> w = (*this)^Fp3_model<n,modulus>::t_minus_1_over_2;
-                let w0: Self = self.clone();
-                w0.pow(P::T_MINUS_1_OVER_2);
+                let w0 = self.pow(P::T_MINUS_1_OVER_2);
                 // Fp3_model<n,modulus> x = (*this) * w;
                 let mut x = w0.clone();
                 x.mul_assign(&self);
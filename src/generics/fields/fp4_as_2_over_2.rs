@@ -2,7 +2,7 @@ use ff::{
     Field, 
 };
 
-use crate::BitIterator;
+use crate::wnaf::naf_form;
 use rand::{Rand, Rng};
 use std::{
     fmt::Debug,
@@ -28,6 +28,14 @@ pub trait Fp4Extension: 'static + Copy + Debug + Eq + Sync + Send {
 
 /// An element of Fp2, represented by c0 + c1 * u.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "Fp2<P::Fp2P>: serde::Serialize",
+        deserialize = "Fp2<P::Fp2P>: serde::Deserialize<'de>"
+    ))
+)]
 pub struct Fp4<P: Fp4Extension> {
     pub c0: Fp2<P::Fp2P>,
     pub c1: Fp2<P::Fp2P>,
@@ -56,17 +64,21 @@ impl<P: Fp4Extension> Fp4<P> {
     }
 
     // When the Fp4 element is known to be an r-th root of 
-    // unity, we can use this function instead of squaring
-    // TODO: Implement NAF
+    // unity, we can use this function instead of pow. Runs over the
+    // non-adjacent form of the exponent, as the conjugate is the inverse.
     #[inline(always)]
     pub fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
-        let mut res = Self::one();
-        let mut found_one = false;
+        let mut inv = *self;
+        inv.conjugate();
 
-        for i in BitIterator::new(exp) {
-            if found_one { res.cyclotomic_square(); }
-            else { found_one = i; }
-            if i { res.mul_assign(self); }
+        let mut res = Self::one();
+        let mut found_nonzero = false;
+        for &digit in naf_form(exp.as_ref()).iter().rev() {
+            if found_nonzero { res.cyclotomic_square(); }
+            if digit != 0 {
+                found_nonzero = true;
+                res.mul_assign(if digit > 0 { self } else { &inv });
+            }
         }
         res
     }
@@ -2,7 +2,7 @@ use ff::{
     Field, 
 };
 
-use crate::BitIterator;
+use crate::wnaf::naf_form;
 use rand::{Rand, Rng};
 use std::{
     fmt::Debug,
@@ -29,6 +29,14 @@ pub trait Fp6Extension: 'static + Copy + Debug + Eq + Sync + Send {
 
 /// An element of Fp3, represented by c0 + c1 * u.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "Fp3<P::Fp3P>: serde::Serialize",
+        deserialize = "Fp3<P::Fp3P>: serde::Deserialize<'de>"
+    ))
+)]
 pub struct Fp6<P: Fp6Extension> {
     pub c0: Fp3<P::Fp3P>,
     pub c1: Fp3<P::Fp3P>,
@@ -57,17 +65,21 @@ impl<P: Fp6Extension> Fp6<P> {
     }
 
     // When the Fp6 element is known to be an r-th root of 
-    // unity, we can use this function instead of pow
-    // TODO: Implement NAF
+    // unity, we can use this function instead of pow. Runs over the
+    // non-adjacent form of the exponent, as the conjugate is the inverse.
     #[inline(always)]
     pub fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
-        let mut res = Self::one();
-        let mut found_one = false;
+        let mut inv = *self;
+        inv.conjugate();
 
-        for i in BitIterator::new(exp) {
-            if found_one { res.cyclotomic_square(); }
-            else { found_one = i; }
-            if i { res.mul_assign(self); }
+        let mut res = Self::one();
+        let mut found_nonzero = false;
+        for &digit in naf_form(exp.as_ref()).iter().rev() {
+            if found_nonzero { res.cyclotomic_square(); }
+            if digit != 0 {
+                found_nonzero = true;
+                res.mul_assign(if digit > 0 { self } else { &inv });
+            }
         }
         res
     }
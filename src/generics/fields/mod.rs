@@ -1,4 +1,29 @@
 pub mod fp2;
 pub mod fp3;
 pub mod fp4_as_2_over_2;
-pub mod fp6_as_2_over_3;
\ No newline at end of file
+pub mod fp6_as_2_over_3;
+use ff::Field;
+
+/// Replaces every element of `v`, none of which may be zero, by its inverse
+/// at the cost of a single inversion, using Montgomery's trick.
+pub(crate) fn batch_inversion<F: Field>(v: &mut [F]) {
+    // First pass: compute [a, ab, abc, ...]
+    let mut prod = Vec::with_capacity(v.len());
+    let mut tmp = F::one();
+    for g in v.iter() {
+        tmp.mul_assign(g);
+        prod.push(tmp);
+    }
+
+    tmp = tmp.inverse().expect("elements are non-zero");
+
+    // Second pass: iterate backwards to compute inverses
+    for (g, s) in v.iter_mut().rev().zip(prod.into_iter().rev().skip(1).chain(Some(F::one()))) {
+        // tmp := tmp * g; g := tmp * s = 1/g
+        let mut newtmp = tmp;
+        newtmp.mul_assign(g);
+        *g = tmp;
+        g.mul_assign(&s);
+        tmp = newtmp;
+    }
+}
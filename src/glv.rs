@@ -0,0 +1,178 @@
+use super::wnaf::{wnaf_form, wnaf_table};
+use super::{CurveProjective, PrimeField, PrimeFieldRepr};
+
+/// Parameters of the GLV/GLS decomposition for an endomorphism `phi` that
+/// acts on the prime order subgroup as multiplication by some `lambda`.
+///
+/// A scalar `k` is split as `k = k_0 + k_1 * lambda + ... + k_{n-1} *
+/// lambda^{n-1} mod r`, where the `k_i` are about `1 / n` the size of `r`, by
+/// rounding `(k, 0, ..., 0)` to a nearby vector of the lattice
+/// `{(x_0, ..., x_{n-1}) : sum_i x_i * lambda^i = 0 mod r}`.
+#[derive(Debug)]
+pub(crate) struct GlvParameters {
+    /// A basis of short vectors of the lattice, with determinant `r`. Each
+    /// entry is a sign (set if negative) and an absolute value.
+    pub(crate) basis: &'static [&'static [(bool, u128)]],
+    /// The first row of the inverse of the basis, multiplied by `2^m`, where
+    /// `m` is the bit size of the scalar repr. Each entry is a sign and the
+    /// floor of the absolute value, as little-endian limbs.
+    pub(crate) g: &'static [(bool, &'static [u64])],
+}
+
+fn repr_from_limbs<R: PrimeFieldRepr>(limbs: &[u64]) -> R {
+    let mut repr = R::default();
+    repr.as_mut()[..limbs.len()].copy_from_slice(limbs);
+    repr
+}
+
+fn from_signed<F: PrimeField>(neg: bool, repr: F::Repr) -> F {
+    let mut res = F::from_repr(repr).expect("GLV constants are reduced");
+    if neg {
+        res.negate();
+    }
+    res
+}
+
+/// Reduces `k` modulo the characteristic of `F`.
+fn reduce<F: PrimeField>(mut k: F::Repr) -> F {
+    let modulus = F::char();
+    while k >= modulus {
+        k.sub_noborrow(&modulus);
+    }
+
+    F::from_repr(k).unwrap()
+}
+
+/// Computes `floor(k * g / 2^m)`, where `m` is the bit size of `k`'s repr.
+fn mul_shift<R: PrimeFieldRepr>(k: &R, g: &[u64]) -> R {
+    let k = k.as_ref();
+    let mut product = vec![0u64; k.len() + g.len()];
+
+    for (i, a) in k.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, b) in g.iter().enumerate() {
+            let t = (*a as u128) * (*b as u128) + (product[i + j] as u128) + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + g.len()] = carry as u64;
+    }
+
+    let high = &product[k.len()..];
+    let len = high.iter().rposition(|l| *l != 0).map_or(0, |i| i + 1);
+
+    repr_from_limbs(&high[..len])
+}
+
+/// Returns the sign and the absolute value of `x` viewed as an integer in
+/// `(-r / 2, r / 2)`.
+fn to_signed<F: PrimeField>(x: F) -> (bool, F::Repr) {
+    let mut neg = x;
+    neg.negate();
+
+    let (x, neg) = (x.into_repr(), neg.into_repr());
+    if x > neg {
+        (true, neg)
+    } else {
+        (false, x)
+    }
+}
+
+/// Splits `k` into `(k_0, ..., k_{n-1})` with `k = sum_i k_i * lambda^i mod
+/// r`, each given as a sign and an absolute value.
+pub(crate) fn glv_decompose<F: PrimeField>(
+    k: F::Repr,
+    params: &GlvParameters,
+) -> Vec<(bool, F::Repr)> {
+    let k = reduce::<F>(k);
+    let k_repr = k.into_repr();
+
+    // c = (k, 0, ..., 0) * basis^-1, rounded down
+    let c: Vec<F> = params
+        .g
+        .iter()
+        .map(|(neg, g)| from_signed(*neg, mul_shift(&k_repr, g)))
+        .collect();
+
+    // (k, 0, ..., 0) - c * basis
+    (0..params.basis.len())
+        .map(|i| {
+            let mut k_i = if i == 0 { k } else { F::zero() };
+            for (c_j, v_j) in c.iter().zip(params.basis.iter()) {
+                let (neg, v) = v_j[i];
+                let mut tmp: F = from_signed(neg, repr_from_limbs(&[v as u64, (v >> 64) as u64]));
+                tmp.mul_assign(c_j);
+                k_i.sub_assign(&tmp);
+            }
+
+            to_signed(k_i)
+        })
+        .collect()
+}
+
+/// Computes `k * base` for `base` in the prime order subgroup, given the
+/// endomorphism `phi` that acts on it as multiplication by the `lambda` of
+/// `params`. The parts of the decomposed scalar are processed with
+/// interleaved w-NAF, sharing the doublings.
+pub(crate) fn glv_mul<G: CurveProjective>(
+    base: &G,
+    k: <G::Scalar as PrimeField>::Repr,
+    params: &GlvParameters,
+    phi: impl Fn(&G) -> G,
+) -> G {
+    let parts = glv_decompose::<G::Scalar>(k, params);
+
+    let largest = parts.iter().map(|(_, k_i)| *k_i).max().unwrap();
+    let window = G::recommended_wnaf_for_scalar(largest);
+
+    // The table for phi^i(base) is phi applied to the one for phi^{i-1}(base)
+    let mut table = vec![];
+    wnaf_table(&mut table, *base, window);
+    let mut tables = vec![table];
+    for _ in 1..parts.len() {
+        let next = tables.last().unwrap().iter().map(&phi).collect();
+        tables.push(next);
+    }
+
+    let mut wnafs = vec![];
+    for ((neg, k_i), table) in parts.iter().zip(tables.iter_mut()) {
+        if *neg {
+            for p in table.iter_mut() {
+                p.negate();
+            }
+        }
+
+        let mut wnaf = vec![];
+        wnaf_form(&mut wnaf, *k_i, window);
+        wnafs.push(wnaf);
+    }
+
+    let terms: Vec<(&[G], &[i64])> = tables
+        .iter()
+        .zip(wnafs.iter())
+        .map(|(table, wnaf)| (&table[..], &wnaf[..]))
+        .collect();
+
+    wnaf_exp_interleaved(&terms)
+}
+
+/// Computes the sum of the w-NAF exponentiations of several `(table, wnaf)`
+/// pairs, sharing the doublings between them.
+pub(crate) fn wnaf_exp_interleaved<G: CurveProjective>(terms: &[(&[G], &[i64])]) -> G {
+    let len = terms.iter().map(|(_, wnaf)| wnaf.len()).max().unwrap_or(0);
+
+    let mut result = G::zero();
+    for i in (0..len).rev() {
+        result.double();
+
+        for (table, wnaf) in terms.iter() {
+            match wnaf.get(i) {
+                Some(n) if *n > 0 => result.add_assign(&table[(n / 2) as usize]),
+                Some(n) if *n < 0 => result.sub_assign(&table[((-n) / 2) as usize]),
+                _ => {}
+            }
+        }
+    }
+
+    result
+}
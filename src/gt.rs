@@ -0,0 +1,347 @@
+//! The target group of a pairing.
+//!
+//! Pairings land in the order `r` subgroup of `Fqk^*`, which is contained in
+//! the cyclotomic subgroup of `Fqk`: elements of norm one over the subfield
+//! `F` of half the degree. There the inverse of `c0 + c1 * w` is simply its
+//! conjugate `c0 - c1 * w`.
+//!
+//! The uncompressed encoding of an element is `c1 || c0`, each subfield
+//! element written as its coefficients over `Fq` from the highest degree
+//! down in big-endian. The compressed encoding is that of the element on an
+//! algebraic torus, see the `torus` module: `T6` for the degree 12 fields of
+//! BLS12 and BN curves, a third of the uncompressed size, and `T2`, half of
+//! it, otherwise.
+
+use super::{Engine, GroupDecodingError, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
+use crate::torus::T2;
+use crate::wnaf::wnaf_form;
+use ff::Field;
+use rand::{Rand, Rng};
+use std::fmt;
+
+/// An extension field `Fqk = F[w] / (w^2 - v)` hosting the target group of a
+/// pairing, where `F` is its subfield of half the degree.
+pub trait TargetField: Field {
+    /// The subfield `F`.
+    type Subfield: Field;
+
+    /// The size in bytes of the encoding of an element of `F`.
+    const SUBFIELD_SIZE: usize;
+
+    /// The size in bytes of the compressed encoding of an element of the
+    /// cyclotomic subgroup.
+    const COMPRESSED_SIZE: usize = Self::SUBFIELD_SIZE;
+
+    /// Returns `c0 + c1 * w`.
+    fn from_subfield(c0: Self::Subfield, c1: Self::Subfield) -> Self;
+
+    /// Returns the coefficients `(c0, c1)` of this element `c0 + c1 * w`.
+    fn into_subfield(self) -> (Self::Subfield, Self::Subfield);
+
+    /// Multiplies an element of `F` by the non-residue `v`.
+    fn mul_subfield_by_nonresidue(c: &mut Self::Subfield);
+
+    /// Writes the encoding of an element of `F` into `bytes`, which must be
+    /// `SUBFIELD_SIZE` long.
+    fn write_subfield(c: &Self::Subfield, bytes: &mut [u8]);
+
+    /// Reads an element of `F` from `bytes`, which must be `SUBFIELD_SIZE`
+    /// long, rejecting non-canonical coefficients.
+    fn read_subfield(bytes: &[u8]) -> Result<Self::Subfield, PrimeFieldDecodingError>;
+
+    /// Replaces this element `c0 + c1 * w` by `c0 - c1 * w`, its inverse if
+    /// it is in the cyclotomic subgroup.
+    fn conjugate(&mut self) {
+        let (c0, mut c1) = self.into_subfield();
+        c1.negate();
+        *self = Self::from_subfield(c0, c1);
+    }
+
+    /// Exponentiates an element of the cyclotomic subgroup, which may be
+    /// faster than `pow`.
+    fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        self.pow(exp)
+    }
+
+    /// Writes the compressed encoding of this element, which must be in the
+    /// cyclotomic subgroup, into `bytes`, which must be `COMPRESSED_SIZE`
+    /// long.
+    fn write_compressed(&self, bytes: &mut [u8]) {
+        T2::compress_unchecked(self).write(bytes);
+    }
+
+    /// Reads a compressed element of the cyclotomic subgroup from `bytes`,
+    /// which must be `COMPRESSED_SIZE` long.
+    fn read_compressed(bytes: &[u8]) -> Result<Self, GroupDecodingError> {
+        Ok(T2::<Self>::read(bytes)?.decompress())
+    }
+}
+
+/// Writes a prime field element as its big-endian canonical representation.
+pub(crate) fn write_fq<F: PrimeField>(f: &F, bytes: &mut [u8]) {
+    f.into_repr().write_be(bytes).expect("buffer is large enough");
+}
+
+/// Reads a prime field element from its big-endian canonical representation.
+pub(crate) fn read_fq<F: PrimeField>(bytes: &[u8]) -> Result<F, PrimeFieldDecodingError> {
+    let mut repr = F::Repr::default();
+    repr.read_be(bytes).expect("buffer is large enough");
+
+    F::from_repr(repr)
+}
+
+/// An element of the target group of the pairing of `E`.
+pub trait TargetGroup:
+    Sized + Copy + Clone + Send + Sync + fmt::Debug + fmt::Display + PartialEq + Eq + Rand + 'static
+{
+    type Engine: Engine<Fr = Self::Scalar, Fqk = Self::Fqk>;
+    type Scalar: PrimeField;
+    type Fqk: TargetField;
+
+    /// Returns the identity of the group.
+    fn one() -> Self;
+
+    /// Returns the fixed generator of the group, the pairing of the
+    /// generators of G1 and G2.
+    fn generator() -> Self;
+
+    /// Determines if this element is the identity.
+    fn is_one(&self) -> bool;
+
+    /// Multiplies this element by another.
+    fn mul_assign(&mut self, other: &Self);
+
+    /// Squares this element.
+    fn square(&mut self);
+
+    /// Returns the inverse of this element.
+    fn inverse(&self) -> Self;
+
+    /// Raises this element to the power of a scalar.
+    fn pow<S: Into<<Self::Scalar as PrimeField>::Repr>>(&self, exp: S) -> Self;
+
+    /// Computes the product of `bases[i]^exps[i]`, sharing the squarings
+    /// between all terms.
+    fn multiexp(bases: &[Self], exps: &[Self::Scalar]) -> Self;
+
+    /// Wraps a pairing result, checking that it lies in the order `r`
+    /// subgroup.
+    fn from_fqk(f: Self::Fqk) -> Result<Self, GroupDecodingError>;
+
+    /// Wraps a pairing result without checking that it lies in the order `r`
+    /// subgroup. Group operations on elements outside of it give meaningless
+    /// results.
+    fn from_fqk_unchecked(f: Self::Fqk) -> Self;
+
+    /// Returns the underlying element of `Fqk`.
+    fn into_fqk(self) -> Self::Fqk;
+
+    /// Determines if this element lies in the order `r` subgroup, which is
+    /// only in doubt for elements built with `from_fqk_unchecked`.
+    fn is_in_subgroup(&self) -> bool;
+
+    /// Returns the uncompressed encoding of this element.
+    fn into_uncompressed(self) -> Vec<u8>;
+
+    /// Decodes an uncompressed element, checking that it lies in the order
+    /// `r` subgroup.
+    fn from_uncompressed(bytes: &[u8]) -> Result<Self, GroupDecodingError>;
+
+    /// Returns the compressed encoding of this element, a third or half the
+    /// size of the uncompressed one depending on the engine.
+    fn into_compressed(self) -> Vec<u8>;
+
+    /// Decodes a compressed element, checking that it lies in the order `r`
+    /// subgroup.
+    fn from_compressed(bytes: &[u8]) -> Result<Self, GroupDecodingError>;
+}
+
+/// An element of the target group of the pairing of `E`, the order `r`
+/// subgroup of `E::Fqk^*`.
+pub struct Gt<E: Engine>(E::Fqk);
+
+impl<E: Engine> Copy for Gt<E> {}
+
+impl<E: Engine> Clone for Gt<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: Engine> PartialEq for Gt<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<E: Engine> Eq for Gt<E> {}
+
+impl<E: Engine> fmt::Debug for Gt<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Gt({:?})", self.0)
+    }
+}
+
+impl<E: Engine> fmt::Display for Gt<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Gt({})", self.0)
+    }
+}
+
+impl<E: Engine> Rand for Gt<E> {
+    fn rand<R: Rng>(rng: &mut R) -> Self {
+        Self::generator().pow(E::Fr::rand(rng))
+    }
+}
+
+impl<E: Engine> TargetGroup for Gt<E> {
+    type Engine = E;
+    type Scalar = E::Fr;
+    type Fqk = E::Fqk;
+
+    fn one() -> Self {
+        Gt(E::Fqk::one())
+    }
+
+    fn generator() -> Self {
+        Gt(E::gt_generator())
+    }
+
+    fn is_one(&self) -> bool {
+        self.0 == E::Fqk::one()
+    }
+
+    fn mul_assign(&mut self, other: &Self) {
+        self.0.mul_assign(&other.0);
+    }
+
+    fn square(&mut self) {
+        self.0.square();
+    }
+
+    fn inverse(&self) -> Self {
+        let mut res = self.0;
+        res.conjugate();
+
+        Gt(res)
+    }
+
+    fn pow<S: Into<<E::Fr as PrimeField>::Repr>>(&self, exp: S) -> Self {
+        Gt(self.0.cyclotomic_exp(exp.into()))
+    }
+
+    fn multiexp(bases: &[Self], exps: &[E::Fr]) -> Self {
+        assert_eq!(bases.len(), exps.len());
+
+        const WINDOW: usize = 4;
+
+        // Odd powers b, b^3, ..., b^(2^WINDOW - 1) of every base.
+        let tables: Vec<Vec<E::Fqk>> = bases
+            .iter()
+            .map(|b| {
+                let mut sq = b.0;
+                sq.square();
+
+                let mut table = Vec::with_capacity(1 << (WINDOW - 1));
+                let mut acc = b.0;
+                for _ in 0..(1 << (WINDOW - 1)) {
+                    table.push(acc);
+                    acc.mul_assign(&sq);
+                }
+
+                table
+            })
+            .collect();
+
+        let wnafs: Vec<Vec<i64>> = exps
+            .iter()
+            .map(|e| {
+                let mut wnaf = vec![];
+                wnaf_form(&mut wnaf, e.into_repr(), WINDOW);
+                wnaf
+            })
+            .collect();
+
+        let len = wnafs.iter().map(|w| w.len()).max().unwrap_or(0);
+        let mut res = E::Fqk::one();
+        for i in (0..len).rev() {
+            res.square();
+
+            for (table, wnaf) in tables.iter().zip(wnafs.iter()) {
+                match wnaf.get(i) {
+                    Some(&n) if n > 0 => res.mul_assign(&table[(n / 2) as usize]),
+                    Some(&n) if n < 0 => {
+                        // Negative digits use the inverse, i.e. the conjugate.
+                        let mut tmp = table[((-n) / 2) as usize];
+                        tmp.conjugate();
+                        res.mul_assign(&tmp);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Gt(res)
+    }
+
+    fn from_fqk(f: E::Fqk) -> Result<Self, GroupDecodingError> {
+        let res = Gt(f);
+        if res.is_in_subgroup() {
+            Ok(res)
+        } else {
+            Err(GroupDecodingError::NotInSubgroup)
+        }
+    }
+
+    fn from_fqk_unchecked(f: E::Fqk) -> Self {
+        Gt(f)
+    }
+
+    fn into_fqk(self) -> E::Fqk {
+        self.0
+    }
+
+    fn is_in_subgroup(&self) -> bool {
+        !self.0.is_zero() && self.0.pow(E::Fr::char()) == E::Fqk::one()
+    }
+
+    fn into_uncompressed(self) -> Vec<u8> {
+        let size = E::Fqk::SUBFIELD_SIZE;
+        let (c0, c1) = self.0.into_subfield();
+
+        let mut res = vec![0u8; 2 * size];
+        E::Fqk::write_subfield(&c1, &mut res[..size]);
+        E::Fqk::write_subfield(&c0, &mut res[size..]);
+
+        res
+    }
+
+    fn from_uncompressed(bytes: &[u8]) -> Result<Self, GroupDecodingError> {
+        let size = E::Fqk::SUBFIELD_SIZE;
+        if bytes.len() != 2 * size {
+            return Err(GroupDecodingError::UnexpectedInformation);
+        }
+
+        let c1 = E::Fqk::read_subfield(&bytes[..size])
+            .map_err(|e| GroupDecodingError::CoordinateDecodingError("c1", e))?;
+        let c0 = E::Fqk::read_subfield(&bytes[size..])
+            .map_err(|e| GroupDecodingError::CoordinateDecodingError("c0", e))?;
+
+        Self::from_fqk(E::Fqk::from_subfield(c0, c1))
+    }
+
+    fn into_compressed(self) -> Vec<u8> {
+        let mut res = vec![0u8; E::Fqk::COMPRESSED_SIZE];
+        self.0.write_compressed(&mut res);
+
+        res
+    }
+
+    fn from_compressed(bytes: &[u8]) -> Result<Self, GroupDecodingError> {
+        if bytes.len() != E::Fqk::COMPRESSED_SIZE {
+            return Err(GroupDecodingError::UnexpectedInformation);
+        }
+
+        Self::from_fqk(E::Fqk::read_compressed(bytes)?)
+    }
+}
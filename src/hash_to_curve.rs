@@ -0,0 +1,306 @@
+use ff::{Field, LegendreSymbol, PrimeField, PrimeFieldRepr, SqrtField};
+use sha2::{Digest, Sha256};
+
+use super::CurveProjective;
+
+/// Hashing of arbitrary byte strings to points of a prime order group, as
+/// specified by RFC 9380 ("Hashing to Elliptic Curves").
+pub trait HashToCurve: CurveProjective {
+    /// Hashes `msg` to a point of the group using the domain separation tag
+    /// `dst`. The output is indistinguishable from a uniformly random point
+    /// (the `_RO_` suites of RFC 9380).
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self;
+
+    /// Encodes `msg` as a point of the group using the domain separation tag
+    /// `dst`. This is cheaper than `hash_to_curve`, but the output is not
+    /// uniformly distributed (the `_NU_` suites of RFC 9380).
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Self;
+}
+
+/// Produces `len_in_bytes` uniformly random bytes from `msg` and the domain
+/// separation tag `dst` using `expand_message_xmd` with SHA-256 (RFC 9380,
+/// section 5.3.1). Tags longer than 255 bytes are hashed down as described
+/// in section 5.3.3.
+///
+/// # Panics
+///
+/// Panics if `len_in_bytes` exceeds 8160, the most the construction allows.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    const B_IN_BYTES: usize = 32;
+    const R_IN_BYTES: usize = 64;
+
+    let ell = len_in_bytes.div_ceil(B_IN_BYTES);
+    assert!(ell <= 255, "expand_message_xmd cannot produce {} bytes", len_in_bytes);
+
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = Sha256::new()
+            .chain(b"H2C-OVERSIZE-DST-")
+            .chain(dst)
+            .finalize();
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = Sha256::new()
+        .chain([0u8; R_IN_BYTES])
+        .chain(msg)
+        .chain([(len_in_bytes >> 8) as u8, len_in_bytes as u8, 0])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+
+    let mut b_i = Sha256::new()
+        .chain(b_0)
+        .chain([1u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    uniform_bytes.extend_from_slice(&b_i);
+
+    for i in 2..=ell {
+        // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+        let mut xored = [0u8; B_IN_BYTES];
+        for (x, (a, b)) in xored.iter_mut().zip(b_0.iter().zip(b_i.iter())) {
+            *x = a ^ b;
+        }
+
+        b_i = Sha256::new()
+            .chain(xored)
+            .chain([i as u8])
+            .chain(dst)
+            .chain(dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Hashes `msg` to `count` elements of the prime field `F` (RFC 9380, section
+/// 5.2). Elements of an extension of degree `m` are made of `m` consecutive
+/// outputs, lowest coefficient first.
+pub(crate) fn hash_to_field<F: PrimeField>(msg: &[u8], dst: &[u8], count: usize) -> Vec<F> {
+    // L = ceil((ceil(log2(p)) + k) / 8) for the security parameter k = 128
+    let len_per_elm = (F::NUM_BITS as usize + 128).div_ceil(8);
+    let uniform_bytes = expand_message_xmd(msg, dst, count * len_per_elm);
+
+    uniform_bytes
+        .chunks(len_per_elm)
+        .map(from_be_bytes_mod_order)
+        .collect()
+}
+
+/// Interprets a big-endian byte string of any length as an integer and reduces
+/// it modulo the characteristic of `F`.
+pub(crate) fn from_be_bytes_mod_order<F: PrimeField>(bytes: &[u8]) -> F {
+    let mut shift = F::Repr::from(1);
+    shift.shl(64);
+    let shift = F::from_repr(shift).unwrap();
+
+    let mut res = F::zero();
+    for chunk in bytes.rchunks(8).rev() {
+        let limb = chunk.iter().fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+
+        res.mul_assign(&shift);
+        res.add_assign(&F::from_repr(F::Repr::from(limb)).unwrap());
+    }
+
+    res
+}
+
+/// The `sgn0` function of RFC 9380 (section 4.1), which defines the "sign"
+/// of a field element.
+pub(crate) trait Sgn0 {
+    fn sgn0(&self) -> bool;
+}
+
+/// Simplified Shallue-van de Woestijne-Ulas map (RFC 9380, section 6.6.2) to
+/// the curve y^2 = x^3 + a * x + b, with a * b != 0 and `z` chosen as the RFC
+/// requires. Returns the affine coordinates of the point.
+pub(crate) fn map_to_curve_simple_swu<F: SqrtField + Sgn0>(u: &F, a: &F, b: &F, z: &F) -> (F, F) {
+    // Z * u^2
+    let mut z_u2 = *u;
+    z_u2.square();
+    z_u2.mul_assign(z);
+
+    // Z^2 * u^4 + Z * u^2
+    let mut den = z_u2;
+    den.square();
+    den.add_assign(&z_u2);
+
+    let x1 = if den.is_zero() {
+        // Exceptional case: x1 = B / (Z * A)
+        let mut x1 = *z;
+        x1.mul_assign(a);
+        x1 = x1.inverse().unwrap();
+        x1.mul_assign(b);
+        x1
+    } else {
+        // x1 = (-B / A) * (1 + 1 / (Z^2 * u^4 + Z * u^2))
+        let mut x1 = den;
+        x1.add_assign(&F::one());
+        x1.mul_assign(b);
+        x1.negate();
+        den.mul_assign(a);
+        x1.mul_assign(&den.inverse().unwrap());
+        x1
+    };
+
+    let (x, mut y) = match curve_rhs(&x1, a, b).sqrt() {
+        Some(y1) => (x1, y1),
+        None => {
+            // If g(x1) is not square then g(Z * u^2 * x1) is.
+            let mut x2 = z_u2;
+            x2.mul_assign(&x1);
+            let y2 = curve_rhs(&x2, a, b)
+                .sqrt()
+                .expect("g(x2) is square whenever g(x1) is not");
+            (x2, y2)
+        }
+    };
+
+    if u.sgn0() != y.sgn0() {
+        y.negate();
+    }
+
+    (x, y)
+}
+
+/// Shallue-van de Woestijne map (RFC 9380, section 6.6.1) to the curve
+/// y^2 = x^3 + a * x + b. The constants `c` are c1 to c4 of the RFC, derived
+/// from `z`. Returns the affine coordinates of the point.
+pub(crate) fn map_to_curve_svdw<F: SqrtField + Sgn0>(u: &F, a: &F, b: &F, z: &F, c: &[F; 4]) -> (F, F) {
+    let is_square = |x: &F| x.legendre() != LegendreSymbol::QuadraticNonResidue;
+
+    // tv1 = u^2 * c1, tv2 = 1 + tv1, tv1 = 1 - tv1
+    let mut tv1 = *u;
+    tv1.square();
+    tv1.mul_assign(&c[0]);
+    let mut tv2 = F::one();
+    tv2.add_assign(&tv1);
+    tv1.negate();
+    tv1.add_assign(&F::one());
+
+    // tv3 = inv0(tv1 * tv2)
+    let mut tv3 = tv1;
+    tv3.mul_assign(&tv2);
+    let tv3 = tv3.inverse().unwrap_or_else(F::zero);
+
+    // tv4 = u * tv1 * tv3 * c3
+    let mut tv4 = *u;
+    tv4.mul_assign(&tv1);
+    tv4.mul_assign(&tv3);
+    tv4.mul_assign(&c[2]);
+
+    // x1 = c2 - tv4
+    let mut x1 = c[1];
+    x1.sub_assign(&tv4);
+    // x2 = c2 + tv4
+    let mut x2 = c[1];
+    x2.add_assign(&tv4);
+    // x3 = Z + c4 * (tv2^2 * tv3)^2
+    let mut x3 = tv2;
+    x3.square();
+    x3.mul_assign(&tv3);
+    x3.square();
+    x3.mul_assign(&c[3]);
+    x3.add_assign(z);
+
+    let x = if is_square(&curve_rhs(&x1, a, b)) {
+        x1
+    } else if is_square(&curve_rhs(&x2, a, b)) {
+        x2
+    } else {
+        x3
+    };
+
+    let mut y = curve_rhs(&x, a, b)
+        .sqrt()
+        .expect("one of g(x1), g(x2), g(x3) is square");
+
+    if u.sgn0() != y.sgn0() {
+        y.negate();
+    }
+
+    (x, y)
+}
+
+/// Computes x^3 + a * x + b.
+fn curve_rhs<F: Field>(x: &F, a: &F, b: &F) -> F {
+    let mut res = *x;
+    res.square();
+    res.add_assign(a);
+    res.mul_assign(x);
+    res.add_assign(b);
+    res
+}
+
+/// Evaluates the rational map (x_num(x) / x_den(x), y * y_num(x) / y_den(x))
+/// at the point (x, y). Coefficients are given lowest degree first. Returns
+/// `None` if the point is sent to infinity.
+pub(crate) fn isogeny_map<F: Field>(
+    x: &F,
+    y: &F,
+    x_num: &[F],
+    x_den: &[F],
+    y_num: &[F],
+    y_den: &[F],
+) -> Option<(F, F)> {
+    fn horner<F: Field>(coeffs: &[F], x: &F) -> F {
+        let mut res = F::zero();
+        for c in coeffs.iter().rev() {
+            res.mul_assign(x);
+            res.add_assign(c);
+        }
+        res
+    }
+
+    let x_den = horner(x_den, x);
+    let y_den = horner(y_den, x);
+
+    // Invert both denominators at once.
+    let mut inv = x_den;
+    inv.mul_assign(&y_den);
+    let inv = inv.inverse()?;
+
+    let mut res_x = horner(x_num, x);
+    res_x.mul_assign(&y_den);
+    res_x.mul_assign(&inv);
+
+    let mut res_y = horner(y_num, x);
+    res_y.mul_assign(y);
+    res_y.mul_assign(&x_den);
+    res_y.mul_assign(&inv);
+
+    Some((res_x, res_y))
+}
+
+#[test]
+fn test_expand_message_xmd() {
+    // Vectors from RFC 9380, appendix K.1
+    let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    assert_eq!(
+        expand_message_xmd(b"", dst, 0x20),
+        [
+            0x68, 0xa9, 0x85, 0xb8, 0x7e, 0xb6, 0xb4, 0x69, 0x52, 0x12, 0x89, 0x11, 0xf2, 0xa4,
+            0x41, 0x2b, 0xbc, 0x30, 0x2a, 0x9d, 0x75, 0x96, 0x67, 0xf8, 0x7f, 0x7a, 0x21, 0xd8,
+            0x03, 0xf0, 0x72, 0x35,
+        ]
+    );
+    assert_eq!(
+        expand_message_xmd(b"abc", dst, 0x20),
+        [
+            0xd8, 0xcc, 0xab, 0x23, 0xb5, 0x98, 0x5c, 0xce, 0xa8, 0x65, 0xc6, 0xc9, 0x7b, 0x6e,
+            0x5b, 0x83, 0x50, 0xe7, 0x94, 0xe6, 0x03, 0xb4, 0xb9, 0x79, 0x02, 0xf5, 0x3a, 0x8a,
+            0x0d, 0x60, 0x56, 0x15,
+        ]
+    );
+    assert_eq!(expand_message_xmd(b"abc", dst, 0x80).len(), 0x80);
+}
@@ -13,6 +13,7 @@
 
 extern crate byteorder;
 extern crate rand;
+extern crate sha2;
 
 #[cfg(test)]
 pub mod tests;
@@ -21,12 +22,43 @@ pub extern crate ff;
 
 pub use ff::*;
 
+#[cfg(feature = "serde")]
+#[macro_use]
+mod serialization;
+
+#[macro_use]
+pub mod ct;
+
 pub mod generics;
 pub mod engines;
 
 mod wnaf;
 pub use self::wnaf::Wnaf;
 
+mod glv;
+
+mod multiexp;
+pub use self::multiexp::{multiexp, multiexp_threaded};
+
+mod fixed_base;
+pub use self::fixed_base::FixedBaseTable;
+
+mod hash_to_curve;
+pub use self::hash_to_curve::{expand_message_xmd, HashToCurve};
+
+mod gt;
+pub use self::gt::{Gt, TargetField, TargetGroup};
+
+mod torus;
+pub use self::torus::{SexticTargetField, T2, T6};
+
+mod prepared;
+
+mod parallel;
+
+mod secret;
+pub use self::secret::SecretScalar;
+
 use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr, ScalarEngine, SqrtField};
 use std::error::Error;
 use std::fmt;
@@ -82,7 +114,10 @@ pub trait Engine: ScalarEngine {
     type Fqe: SqrtField;
 
     /// The extension field that hosts the target group of the pairing.
-    type Fqk: Field;
+    type Fqk: TargetField;
+
+    /// The target group of the pairing.
+    type Gt: TargetGroup<Engine = Self, Scalar = Self::Fr, Fqk = Self::Fqk>;
 
     /// Perform a miller loop with some number of (G1, G2) pairs.
     fn miller_loop<'a, I>(i: I) -> Self::Fqk
@@ -107,6 +142,56 @@ pub trait Engine: ScalarEngine {
             [(&(p.into().prepare()), &(q.into().prepare()))].into_iter(),
         )).unwrap()
     }
+
+    /// Returns the pairing of the generators of G1 and G2, which generates
+    /// the target group. Engines cache it, so only the first call computes
+    /// a pairing.
+    fn gt_generator() -> Self::Fqk {
+        Self::pairing(Self::G1Affine::one(), Self::G2Affine::one())
+    }
+
+    /// Checks whether the product of the pairings `e(p, q)` over all `(p, q)`
+    /// in `terms` is the identity of the target group. This costs a single
+    /// miller loop and final exponentiation.
+    #[allow(clippy::type_complexity)]
+    fn pairing_product_is_one(
+        terms: &[(
+            &<Self::G1Affine as CurveAffine>::Prepared,
+            &<Self::G2Affine as CurveAffine>::Prepared,
+        )],
+    ) -> bool {
+        match Self::final_exponentiation(&Self::miller_loop(terms)) {
+            Some(res) => res == Self::Fqk::one(),
+            None => false,
+        }
+    }
+
+    /// Checks a batch of independent pairing-product equations, each given as
+    /// the `(G1, G2)` pairs whose pairings should multiply to one. The
+    /// equations are combined with random coefficients drawn from `rng` and
+    /// checked with a single `pairing_product_is_one`. If any equation does
+    /// not hold, this returns `false` except with probability about `1 / r`.
+    fn batch_pairing_products_are_one<R: rand::Rng>(
+        equations: &[&[(Self::G1Affine, Self::G2Affine)]],
+        rng: &mut R,
+    ) -> bool {
+        let mut prepared = vec![];
+        for (i, equation) in equations.iter().enumerate() {
+            // Randomizing all but one of the equations is enough.
+            let r: Option<Self::Fr> = if i == 0 { None } else { Some(rng.gen()) };
+
+            for (p, q) in equation.iter() {
+                let p = match r {
+                    Some(r) => p.mul(r).into_affine(),
+                    None => *p,
+                };
+                prepared.push((p.prepare(), q.prepare()));
+            }
+        }
+
+        let terms: Vec<_> = prepared.iter().map(|(p, q)| (p, q)).collect();
+        Self::pairing_product_is_one(&terms)
+    }
 }
 
 /// Projective representation of an elliptic curve point guaranteed to be
@@ -171,6 +256,12 @@ pub trait CurveProjective:
     /// Converts this element into its affine representation.
     fn into_affine(&self) -> Self::Affine;
 
+    /// Maps a point on the curve into the prime order subgroup, by multiplying
+    /// it with the cofactor or with a multiple of it that is cheaper to apply.
+    /// The multiple is fixed for each group, but may differ between groups
+    /// with the same cofactor.
+    fn clear_cofactor(&self) -> Self;
+
     /// Recommends a wNAF window table size given a scalar. Always returns a number
     /// between 2 and 22, inclusive.
     fn recommended_wnaf_for_scalar(scalar: <Self::Scalar as PrimeField>::Repr) -> usize;
@@ -220,6 +311,20 @@ pub trait CurveAffine:
     /// Converts this element into its affine representation.
     fn into_projective(&self) -> Self::Projective;
 
+    /// Determines if this point lies on the curve.
+    fn is_on_curve(&self) -> bool;
+
+    /// Determines if this point lies on the curve and in the prime order
+    /// subgroup. Points obtained through the checked decoding APIs always
+    /// do.
+    fn is_in_subgroup(&self) -> bool;
+
+    /// Maps a point on the curve into the prime order subgroup, as with
+    /// `CurveProjective::clear_cofactor`.
+    fn clear_cofactor(&self) -> Self::Projective {
+        self.into_projective().clear_cofactor()
+    }
+
     /// Converts this element into its compressed encoding, so long as it's not
     /// the point at infinity.
     fn into_compressed(&self) -> Self::Compressed {
@@ -270,6 +375,13 @@ pub trait EncodedPoint:
     /// so caution is strongly encouraged.
     fn into_affine_unchecked(&self) -> Result<Self::Affine, GroupDecodingError>;
 
+    /// Converts many `EncodedPoint`s into `CurveAffine` elements, failing if
+    /// any of them does not represent a valid element. With the `parallel`
+    /// feature, the points are decoded on several threads.
+    fn into_affine_batch(encoded: &[Self]) -> Result<Vec<Self::Affine>, GroupDecodingError> {
+        parallel::try_map(encoded, 16, Self::into_affine)
+    }
+
     /// Creates an `EncodedPoint` from an affine point, as long as the
     /// point is not the point at infinity.
     fn from_affine(affine: Self::Affine) -> Self;
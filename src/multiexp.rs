@@ -0,0 +1,146 @@
+use std::thread;
+
+use super::{parallel, CurveAffine, CurveProjective, PrimeField, PrimeFieldRepr};
+
+/// Computes the multi-scalar multiplication `sum_i scalars[i] * bases[i]` with
+/// Pippenger's bucket method. The window size is chosen from the number of
+/// terms.
+///
+/// The scalars must be canonical representations of elements of the scalar
+/// field; bits above `G::Scalar::NUM_BITS` are ignored. With the `parallel`
+/// feature, this is `multiexp_threaded` with one thread per core.
+///
+/// # Panics
+///
+/// Panics if `bases` and `scalars` have different lengths.
+pub fn multiexp<G: CurveAffine>(
+    bases: &[G],
+    scalars: &[<G::Scalar as PrimeField>::Repr],
+) -> G::Projective {
+    if cfg!(feature = "parallel") {
+        return multiexp_threaded(bases, scalars, parallel::num_threads());
+    }
+
+    assert_eq!(
+        bases.len(),
+        scalars.len(),
+        "multiexp needs as many scalars as bases"
+    );
+
+    pippenger(bases, scalars, window_size(bases.len()))
+}
+
+/// Same as `multiexp`, but splits the terms into `num_threads` chunks that
+/// are processed on separate threads.
+///
+/// # Panics
+///
+/// Panics if `bases` and `scalars` have different lengths, or if
+/// `num_threads` is zero.
+pub fn multiexp_threaded<G: CurveAffine>(
+    bases: &[G],
+    scalars: &[<G::Scalar as PrimeField>::Repr],
+    num_threads: usize,
+) -> G::Projective {
+    assert_eq!(
+        bases.len(),
+        scalars.len(),
+        "multiexp needs as many scalars as bases"
+    );
+    assert!(num_threads > 0, "multiexp needs at least one thread");
+
+    if num_threads == 1 || bases.len() < 2 * num_threads {
+        return pippenger(bases, scalars, window_size(bases.len()));
+    }
+
+    let chunk_size = bases.len().div_ceil(num_threads);
+
+    thread::scope(|s| {
+        let handles: Vec<_> = bases
+            .chunks(chunk_size)
+            .zip(scalars.chunks(chunk_size))
+            .map(|(bases, scalars)| {
+                s.spawn(move || pippenger(bases, scalars, window_size(bases.len())))
+            })
+            .collect();
+
+        let mut acc = G::Projective::zero();
+        for handle in handles {
+            acc.add_assign(&handle.join().unwrap());
+        }
+
+        acc
+    })
+}
+
+/// Picks a window size for `num_terms` terms.
+fn window_size(num_terms: usize) -> usize {
+    if num_terms < 32 {
+        3
+    } else {
+        (num_terms as f64).ln().ceil() as usize
+    }
+}
+
+/// Returns the `width` bits of `repr` starting at bit `start`.
+pub(crate) fn get_bits<R: PrimeFieldRepr>(repr: &R, start: usize, width: usize) -> usize {
+    let limbs = repr.as_ref();
+    let limb = start / 64;
+    let shift = start % 64;
+
+    if limb >= limbs.len() {
+        return 0;
+    }
+
+    let mut bits = limbs[limb] >> shift;
+    if shift + width > 64 && limb + 1 < limbs.len() {
+        bits |= limbs[limb + 1] << (64 - shift);
+    }
+
+    (bits & ((1 << width) - 1)) as usize
+}
+
+fn pippenger<G: CurveAffine>(
+    bases: &[G],
+    scalars: &[<G::Scalar as PrimeField>::Repr],
+    window: usize,
+) -> G::Projective {
+    let num_bits = G::Scalar::NUM_BITS as usize;
+
+    let mut acc = G::Projective::zero();
+    let mut buckets = vec![G::Projective::zero(); (1 << window) - 1];
+
+    // Process the windows from the most significant one down, so that the
+    // accumulator only has to be doubled `window` times between them.
+    let mut start = ((num_bits - 1) / window) * window;
+    loop {
+        for _ in 0..window {
+            acc.double();
+        }
+
+        for bucket in buckets.iter_mut() {
+            *bucket = G::Projective::zero();
+        }
+
+        for (base, scalar) in bases.iter().zip(scalars.iter()) {
+            let index = get_bits(scalar, start, window);
+            if index != 0 && !base.is_zero() {
+                buckets[index - 1].add_assign_mixed(base);
+            }
+        }
+
+        // sum_j j * buckets[j - 1], as a running sum from the top bucket down
+        let mut running_sum = G::Projective::zero();
+        for bucket in buckets.iter().rev() {
+            running_sum.add_assign(bucket);
+            acc.add_assign(&running_sum);
+        }
+
+        if start == 0 {
+            break;
+        }
+        start -= window;
+    }
+
+    acc
+}
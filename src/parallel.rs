@@ -0,0 +1,193 @@
+//! Splitting of bulk work between threads. Without the `parallel` feature,
+//! everything runs on the calling thread.
+
+use std::thread;
+
+use ff::Field;
+
+/// The number of threads to split work between.
+pub(crate) fn num_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Returns the size of the chunks that `len` items should be split into, so
+/// that every thread gets at least `min_chunk` items, or `None` if it is not
+/// worth spawning threads.
+fn chunk_size(len: usize, min_chunk: usize) -> Option<usize> {
+    if !cfg!(feature = "parallel") {
+        return None;
+    }
+
+    let num_threads = num_threads().min(len / min_chunk);
+
+    if num_threads < 2 {
+        None
+    } else {
+        Some(len.div_ceil(num_threads))
+    }
+}
+
+/// Returns the product of `f` over chunks of `items` of at least `min_chunk`
+/// elements.
+pub(crate) fn product<T, F, M>(items: Vec<T>, min_chunk: usize, f: M) -> F
+where
+    T: Send,
+    F: Field,
+    M: Fn(Vec<T>) -> F + Sync,
+{
+    let chunk_size = chunk_size(items.len(), min_chunk);
+    product_in_chunks(items, chunk_size, f)
+}
+
+fn product_in_chunks<T, F, M>(items: Vec<T>, chunk_size: Option<usize>, f: M) -> F
+where
+    T: Send,
+    F: Field,
+    M: Fn(Vec<T>) -> F + Sync,
+{
+    let chunk_size = match chunk_size {
+        Some(chunk_size) => chunk_size,
+        None => return f(items),
+    };
+
+    let mut items = items.into_iter().peekable();
+    let mut chunks = vec![];
+    while items.peek().is_some() {
+        chunks.push(items.by_ref().take(chunk_size).collect::<Vec<_>>());
+    }
+
+    let f = &f;
+    thread::scope(|s| {
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| s.spawn(move || f(chunk)))
+            .collect();
+
+        let mut acc = F::one();
+        for handle in handles {
+            acc.mul_assign(&handle.join().unwrap());
+        }
+
+        acc
+    })
+}
+
+/// Applies `f` to chunks of `v` of at least `min_chunk` elements.
+pub(crate) fn for_each_chunk<T, M>(v: &mut [T], min_chunk: usize, f: M)
+where
+    T: Send,
+    M: Fn(&mut [T]) + Sync,
+{
+    let chunk_size = chunk_size(v.len(), min_chunk);
+    for_each_chunk_of_size(v, chunk_size, f)
+}
+
+fn for_each_chunk_of_size<T, M>(v: &mut [T], chunk_size: Option<usize>, f: M)
+where
+    T: Send,
+    M: Fn(&mut [T]) + Sync,
+{
+    let chunk_size = match chunk_size {
+        Some(chunk_size) => chunk_size,
+        None => return f(v),
+    };
+
+    let f = &f;
+    thread::scope(|s| {
+        for chunk in v.chunks_mut(chunk_size) {
+            s.spawn(move || f(chunk));
+        }
+    });
+}
+
+/// Applies `f` to every element of `v`, stopping at the first error.
+pub(crate) fn try_map<T, R, E, M>(v: &[T], min_chunk: usize, f: M) -> Result<Vec<R>, E>
+where
+    T: Sync,
+    R: Send,
+    E: Send,
+    M: Fn(&T) -> Result<R, E> + Sync,
+{
+    let chunk_size = chunk_size(v.len(), min_chunk);
+    try_map_in_chunks(v, chunk_size, f)
+}
+
+fn try_map_in_chunks<T, R, E, M>(v: &[T], chunk_size: Option<usize>, f: M) -> Result<Vec<R>, E>
+where
+    T: Sync,
+    R: Send,
+    E: Send,
+    M: Fn(&T) -> Result<R, E> + Sync,
+{
+    let chunk_size = match chunk_size {
+        Some(chunk_size) => chunk_size,
+        None => return v.iter().map(f).collect(),
+    };
+
+    let f = &f;
+    thread::scope(|s| {
+        let handles: Vec<_> = v
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(move || chunk.iter().map(f).collect::<Result<Vec<_>, _>>()))
+            .collect();
+
+        let mut res = Vec::with_capacity(v.len());
+        for handle in handles {
+            res.extend(handle.join().unwrap()?);
+        }
+
+        Ok(res)
+    })
+}
+
+#[test]
+fn test_chunks() {
+    use crate::engines::bls12_381::Fr;
+    use rand::{Rand, SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    // too few items to give two threads a chunk each
+    assert_eq!(chunk_size(7, 4), None);
+
+    let v: Vec<Fr> = (0..10).map(|_| Fr::rand(&mut rng)).collect();
+
+    let mut expected = Fr::one();
+    for a in &v {
+        expected.mul_assign(a);
+    }
+
+    let mut squares = v.clone();
+    for a in squares.iter_mut() {
+        a.square();
+    }
+
+    for &chunk_size in [None, Some(1), Some(3), Some(10), Some(20)].iter() {
+        let product = product_in_chunks(v.clone(), chunk_size, |chunk| {
+            let mut acc = Fr::one();
+            for a in &chunk {
+                acc.mul_assign(a);
+            }
+            acc
+        });
+        assert_eq!(product, expected);
+
+        let mut w = v.clone();
+        for_each_chunk_of_size(&mut w, chunk_size, |chunk| {
+            for a in chunk.iter_mut() {
+                a.square();
+            }
+        });
+        assert_eq!(w, squares);
+
+        let res: Result<Vec<_>, ()> = try_map_in_chunks(&v, chunk_size, |a| {
+            let mut a = *a;
+            a.square();
+            Ok(a)
+        });
+        assert_eq!(res.unwrap(), squares);
+
+        let res = try_map_in_chunks(&v, chunk_size, |a| if *a == v[7] { Err(7) } else { Ok(*a) });
+        assert_eq!(res, Err(7));
+    }
+}
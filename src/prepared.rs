@@ -0,0 +1,102 @@
+//! Helpers for the binary encoding of prepared pairing inputs.
+//!
+//! An encoding starts with a version byte and, for prepared elements of G2, a
+//! byte telling which form the lines are in. Then come the uncompressed
+//! encoding of the prepared point and the coefficients, each run of lines
+//! preceded by its length as a big-endian `u32`. Field elements are written as
+//! big-endian integers, the highest coefficient of an extension first.
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use ff::{PrimeField, PrimeFieldRepr};
+use std::error::Error;
+use std::io::{self, Read, Write};
+
+use crate::{CurveAffine, EncodedPoint};
+
+/// The version of the encoding written by this crate.
+pub(crate) const VERSION: u8 = 1;
+
+/// The form of lines computed in projective coordinates.
+pub(crate) const PROJECTIVE_LINES: u8 = 0;
+
+/// The form of lines computed in affine coordinates.
+pub(crate) const AFFINE_LINES: u8 = 1;
+
+pub(crate) fn invalid_data<E: Into<Box<dyn Error + Send + Sync>>>(e: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+pub(crate) fn write_version<W: Write>(writer: &mut W) -> io::Result<()> {
+    writer.write_u8(VERSION)
+}
+
+pub(crate) fn read_version<R: Read>(reader: &mut R) -> io::Result<()> {
+    let version = reader.read_u8()?;
+    if version != VERSION {
+        return Err(invalid_data(format!("unsupported encoding version {}", version)));
+    }
+
+    Ok(())
+}
+
+/// Writes the version and the form of the lines.
+pub(crate) fn write_header<W: Write>(writer: &mut W, form: u8) -> io::Result<()> {
+    write_version(writer)?;
+    writer.write_u8(form)
+}
+
+/// Reads the version and returns the form of the lines.
+pub(crate) fn read_header<R: Read>(reader: &mut R) -> io::Result<u8> {
+    read_version(reader)?;
+
+    let form = reader.read_u8()?;
+    if form != PROJECTIVE_LINES && form != AFFINE_LINES {
+        return Err(invalid_data(format!("unknown line form {}", form)));
+    }
+
+    Ok(form)
+}
+
+pub(crate) fn write_len<W: Write>(writer: &mut W, len: usize) -> io::Result<()> {
+    writer.write_u32::<BigEndian>(len as u32)
+}
+
+/// Reads a length, which must be `expected`.
+pub(crate) fn read_len<R: Read>(reader: &mut R, expected: usize) -> io::Result<()> {
+    let len = reader.read_u32::<BigEndian>()? as usize;
+    if len != expected {
+        return Err(invalid_data(format!("expected {} lines, found {}", expected, len)));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn write_point<G: CurveAffine, W: Write>(writer: &mut W, p: &G) -> io::Result<()> {
+    writer.write_all(p.into_uncompressed().as_ref())
+}
+
+/// Reads an uncompressed point, checking that it is in the subgroup.
+pub(crate) fn read_point<G: CurveAffine, R: Read>(reader: &mut R) -> io::Result<G> {
+    let mut encoded = G::Uncompressed::empty();
+    reader.read_exact(encoded.as_mut())?;
+
+    encoded.into_affine().map_err(invalid_data)
+}
+
+pub(crate) fn write_fq<F: PrimeField, W: Write>(writer: &mut W, f: &F) -> io::Result<()> {
+    f.into_repr().write_be(writer)
+}
+
+/// Reads a field element, rejecting non-canonical encodings.
+pub(crate) fn read_fq<F: PrimeField, R: Read>(reader: &mut R) -> io::Result<F> {
+    let mut repr = F::Repr::default();
+    repr.read_be(reader)?;
+
+    F::from_repr(repr).map_err(invalid_data)
+}
+
+/// The error for coefficients that were not computed from the point next to
+/// them.
+pub(crate) fn mismatched_coefficients() -> io::Error {
+    invalid_data("the coefficients do not match the point")
+}
@@ -0,0 +1,104 @@
+use ff::{PrimeField, ScalarEngine};
+use rand::{Rand, Rng};
+use std::fmt;
+use zeroize::Zeroize;
+
+use crate::ct::{CtCurveAffine, CtCurveProjective};
+use crate::CurveAffine;
+
+/// A secret scalar, such as a private key.
+///
+/// The scalar is zeroized when dropped, is not printed by `Debug`, and can
+/// only be used to multiply points in constant time. It is not `Copy`, so
+/// that it is not left behind in memory by implicit copies, but the value
+/// passed to `new` is an ordinary field element that the caller should
+/// zeroize.
+pub struct SecretScalar<E: ScalarEngine>(E::Fr)
+where
+    E::Fr: Zeroize;
+
+impl<E: ScalarEngine> SecretScalar<E>
+where
+    E::Fr: Zeroize,
+{
+    pub fn new(scalar: E::Fr) -> Self {
+        SecretScalar(scalar)
+    }
+
+    /// Samples a uniformly random scalar.
+    pub fn random<R: Rng>(rng: &mut R) -> Self {
+        SecretScalar(E::Fr::rand(rng))
+    }
+
+    /// Multiplies an affine point by this scalar in constant time.
+    pub fn mul<G: CtCurveAffine<Scalar = E::Fr>>(&self, base: &G) -> G {
+        let mut repr = self.0.into_repr();
+        let res = base.ct_mul(repr);
+        repr.as_mut().zeroize();
+
+        res
+    }
+
+    /// Multiplies a projective point by this scalar in constant time.
+    pub fn mul_projective<G: CtCurveProjective<Scalar = E::Fr>>(&self, base: &G) -> G
+    where
+        G::Affine: CtCurveAffine,
+    {
+        self.mul(&base.ct_into_affine()).into_projective()
+    }
+}
+
+impl<E: ScalarEngine> Clone for SecretScalar<E>
+where
+    E::Fr: Zeroize,
+{
+    fn clone(&self) -> Self {
+        SecretScalar(self.0)
+    }
+}
+
+impl<E: ScalarEngine> Drop for SecretScalar<E>
+where
+    E::Fr: Zeroize,
+{
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<E: ScalarEngine> fmt::Debug for SecretScalar<E>
+where
+    E::Fr: Zeroize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretScalar(..)")
+    }
+}
+
+#[test]
+fn test_secret_scalar() {
+    use crate::engines::bls12_381::{Bls12, Fr, G1Affine, G2};
+    use crate::CurveProjective;
+    use ff::Field;
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    let s = Fr::rand(&mut rng);
+    let secret = SecretScalar::<Bls12>::new(s);
+
+    let p = G1Affine::one();
+    assert_eq!(secret.mul(&p), p.mul(s).into_affine());
+
+    let q = G2::rand(&mut rng);
+    let mut expected = q;
+    expected.mul_assign(s);
+    assert_eq!(secret.clone().mul_projective(&q), expected);
+
+    assert_eq!(format!("{:?}", secret), "SecretScalar(..)");
+
+    // the scalar is still readable after the destructor has run
+    let mut secret = std::mem::ManuallyDrop::new(secret);
+    unsafe { std::ptr::drop_in_place(&mut *secret) };
+    assert!(secret.0.is_zero());
+}
@@ -0,0 +1,136 @@
+//! `serde` support for field elements and curve points, enabled by the
+//! `serde` feature.
+//!
+//! Prime field elements are encoded as their big-endian canonical
+//! representation and points as their `Compressed` encoding. Human-readable
+//! formats get a lowercase hex string, binary formats a fixed-size tuple of
+//! bytes. Deserialization rejects non-canonical field elements and points that
+//! are not on the curve or not in the prime order subgroup.
+
+use super::{CurveAffine, EncodedPoint, PrimeField, PrimeFieldRepr};
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeTuple, Serializer};
+use std::fmt;
+
+/// Implements `Serialize` and `Deserialize` for `$type` in terms of the given
+/// helper functions.
+macro_rules! serde_impl {
+    ($type:ty, $serialize:path, $deserialize:path) => {
+        impl ::serde::Serialize for $type {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $serialize(self, serializer)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $type {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $deserialize(deserializer)
+            }
+        }
+    };
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        let mut hex = String::with_capacity(2 * bytes.len());
+        for b in bytes {
+            hex.push(HEX_DIGITS[(b >> 4) as usize] as char);
+            hex.push(HEX_DIGITS[(b & 0xf) as usize] as char);
+        }
+
+        serializer.serialize_str(&hex)
+    } else {
+        let mut tuple = serializer.serialize_tuple(bytes.len())?;
+        for b in bytes {
+            tuple.serialize_element(b)?;
+        }
+
+        tuple.end()
+    }
+}
+
+/// Fills a buffer of known length from either a hex string or a tuple of
+/// bytes.
+struct BytesVisitor<'a>(&'a mut [u8]);
+
+impl<'a, 'de> Visitor<'de> for BytesVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} bytes", self.0.len())
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<(), E> {
+        let v = v.as_bytes();
+        if v.len() != 2 * self.0.len() {
+            return Err(E::invalid_length(v.len() / 2, &self));
+        }
+
+        for (b, digits) in self.0.iter_mut().zip(v.chunks(2)) {
+            match (hex_digit(digits[0]), hex_digit(digits[1])) {
+                (Some(hi), Some(lo)) => *b = (hi << 4) | lo,
+                _ => return Err(E::custom("invalid hex digit")),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let len = self.0.len();
+        for i in 0..len {
+            self.0[i] = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn deserialize_bytes<'de, D: Deserializer<'de>>(bytes: &mut [u8], deserializer: D) -> Result<(), D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor(bytes))
+    } else {
+        let len = bytes.len();
+        deserializer.deserialize_tuple(len, BytesVisitor(bytes))
+    }
+}
+
+pub(crate) fn serialize_field<F: PrimeField, S: Serializer>(f: &F, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut bytes = vec![];
+    f.into_repr().write_be(&mut bytes).unwrap();
+
+    serialize_bytes(&bytes, serializer)
+}
+
+pub(crate) fn deserialize_field<'de, F: PrimeField, D: Deserializer<'de>>(deserializer: D) -> Result<F, D::Error> {
+    let mut repr = F::Repr::default();
+    let mut bytes = vec![0u8; 8 * repr.as_ref().len()];
+    deserialize_bytes(&mut bytes, deserializer)?;
+    repr.read_be(&bytes[..]).unwrap();
+
+    F::from_repr(repr).map_err(de::Error::custom)
+}
+
+pub(crate) fn serialize_point<G: CurveAffine, S: Serializer>(p: &G, serializer: S) -> Result<S::Ok, S::Error> {
+    serialize_bytes(p.into_compressed().as_ref(), serializer)
+}
+
+pub(crate) fn deserialize_point<'de, G: CurveAffine, D: Deserializer<'de>>(deserializer: D) -> Result<G, D::Error> {
+    let mut compressed = G::Compressed::empty();
+    deserialize_bytes(compressed.as_mut(), deserializer)?;
+
+    compressed.into_affine().map_err(de::Error::custom)
+}
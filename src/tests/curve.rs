@@ -1,7 +1,8 @@
-use ff::Field;
+use ff::{Field, PrimeField, PrimeFieldRepr};
 use rand::{Rand, Rng, SeedableRng, XorShiftRng};
 
-use crate::{CurveAffine, CurveProjective, EncodedPoint};
+use crate::ct::{Choice, ConditionallySelectable, ConstantTimeEq, CtCurveAffine, CtCurveProjective, CtField};
+use crate::{CurveAffine, CurveProjective, EncodedPoint, GroupDecodingError};
 
 pub fn curve_tests<G: CurveProjective>() {
     let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
@@ -62,7 +63,130 @@ pub fn curve_tests<G: CurveProjective>() {
     random_doubling_tests::<G>();
     random_negation_tests::<G>();
     random_wnaf_tests::<G>();
+    random_multiexp_tests::<G>();
+    random_fixed_base_tests::<G>();
     random_encoding_tests::<G::Affine>();
+    random_subgroup_tests::<G>();
+}
+
+fn random_subgroup_tests<G: CurveProjective>() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    assert!(G::Affine::zero().is_on_curve());
+    assert!(G::Affine::zero().is_in_subgroup());
+    assert!(G::zero().clear_cofactor().is_zero());
+    assert!(G::Affine::one().is_in_subgroup());
+
+    for _ in 0..10 {
+        let p = G::rand(&mut rng);
+        let a = p.into_affine();
+        assert!(a.is_on_curve());
+        assert!(a.is_in_subgroup());
+
+        // Clearing the cofactor keeps points in the subgroup
+        let q = p.clear_cofactor();
+        assert_eq!(q, a.clear_cofactor());
+        assert!(q.into_affine().is_in_subgroup());
+    }
+}
+
+fn random_multiexp_tests<G: CurveProjective>() {
+    use ff::PrimeField;
+    use crate::{multiexp, multiexp_threaded};
+
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    for &n in [0, 1, 2, 5, 31, 32, 100].iter() {
+        let mut bases: Vec<G::Affine> = (0..n).map(|_| G::rand(&mut rng).into_affine()).collect();
+        let mut scalars: Vec<_> = (0..n).map(|_| G::Scalar::rand(&mut rng).into_repr()).collect();
+
+        // Exercise the zero base and zero scalar edge cases.
+        if n > 2 {
+            bases[0] = G::Affine::zero();
+            scalars[1] = G::Scalar::zero().into_repr();
+        }
+
+        let mut expected = G::zero();
+        for (base, scalar) in bases.iter().zip(scalars.iter()) {
+            expected.add_assign(&base.mul(*scalar));
+        }
+
+        assert_eq!(multiexp(&bases, &scalars), expected);
+        for &num_threads in [1, 2, 3, 8].iter() {
+            assert_eq!(multiexp_threaded(&bases, &scalars, num_threads), expected);
+        }
+    }
+
+    // The largest scalar touches every window.
+    {
+        let base = G::rand(&mut rng).into_affine();
+        let mut minus_one = G::Scalar::one();
+        minus_one.negate();
+
+        let mut expected = base.into_projective();
+        expected.negate();
+
+        assert_eq!(multiexp(&[base], &[minus_one.into_repr()]), expected);
+    }
+}
+
+fn random_fixed_base_tests<G: CurveProjective>() {
+    use crate::FixedBaseTable;
+
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    for window in 1..6 {
+        let base = G::rand(&mut rng);
+        let table = FixedBaseTable::new(base, window);
+        assert_eq!(table.window_size(), window);
+
+        for _ in 0..20 {
+            let s = G::Scalar::rand(&mut rng);
+            let mut expected = base;
+            expected.mul_assign(s);
+
+            assert_eq!(table.mul(s), expected);
+        }
+
+        let mut minus_one = G::Scalar::one();
+        minus_one.negate();
+        let mut expected = base;
+        expected.negate();
+        assert_eq!(table.mul(minus_one), expected);
+        assert!(table.mul(G::Scalar::zero()).is_zero());
+    }
+
+    // Serialization round trip
+    {
+        let table = FixedBaseTable::new(G::rand(&mut rng), 2);
+
+        let mut bytes = vec![];
+        table.write(&mut bytes).unwrap();
+
+        assert_eq!(FixedBaseTable::<G>::read(&bytes[..]).unwrap(), table);
+        assert_eq!(FixedBaseTable::<G>::read_unchecked(&bytes[..]).unwrap(), table);
+
+        // Truncated input
+        assert!(FixedBaseTable::<G>::read(&bytes[..bytes.len() - 1]).is_err());
+
+        // A valid point that is not the expected multiple of the base
+        {
+            let size = <G::Affine as CurveAffine>::Uncompressed::size();
+            let other = G::rand(&mut rng).into_affine().into_uncompressed();
+
+            let mut tampered = bytes.clone();
+            tampered[4 + 2 * size..4 + 3 * size].copy_from_slice(other.as_ref());
+            assert!(FixedBaseTable::<G>::read(&tampered[..]).is_err());
+            assert!(FixedBaseTable::<G>::read_unchecked(&tampered[..]).is_ok());
+        }
+
+        // The largest window must not be allocated up front
+        assert!(FixedBaseTable::<G>::read(&[0, 0, 0, 22][..]).is_err());
+
+        // Bad window size
+        bytes[3] = 0;
+        assert!(FixedBaseTable::<G>::read(&bytes[..]).is_err());
+    }
 }
 
 fn random_wnaf_tests<G: CurveProjective>() {
@@ -266,6 +390,46 @@ fn random_multiplication_tests<G: CurveProjective>() {
     }
 }
 
+/// Compares a multiplication that uses an endomorphism, and so is only
+/// correct in the prime order subgroup, with `mul_assign`, including for
+/// scalars that are not reduced modulo r.
+pub fn endomorphism_mul_tests<G, M>(mul: M)
+where
+    G: CurveProjective,
+    M: Fn(&G, <G::Scalar as PrimeField>::Repr) -> G,
+{
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    let mut minus_one = G::Scalar::one();
+    minus_one.negate();
+    let mut char_plus_one = G::Scalar::char();
+    char_plus_one.add_nocarry(&1.into());
+    let mut all_ones = G::Scalar::char();
+    for limb in all_ones.as_mut() {
+        *limb = u64::max_value();
+    }
+
+    let mut scalars = vec![
+        G::Scalar::zero().into_repr(),
+        G::Scalar::one().into_repr(),
+        minus_one.into_repr(),
+        G::Scalar::char(),
+        char_plus_one,
+        all_ones,
+    ];
+    scalars.extend((0..100).map(|_| G::Scalar::rand(&mut rng).into_repr()));
+
+    assert!(mul(&G::zero(), G::Scalar::rand(&mut rng).into_repr()).is_zero());
+
+    for s in scalars {
+        let a = G::rand(&mut rng);
+
+        let mut expected = a;
+        expected.mul_assign(s);
+        assert_eq!(mul(&a, s), expected);
+    }
+}
+
 fn random_addition_tests<G: CurveProjective>() {
     let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
 
@@ -427,6 +591,46 @@ pub fn random_transformation_tests_with_cofactor<G: CurveProjective>() {
     }
 }
 
+/// The coordinate accessors and constructors that each engine defines as
+/// inherent methods on its affine points.
+pub trait XyConstructors: CurveAffine {
+    fn x(&self) -> Self::Base;
+    fn y(&self) -> Self::Base;
+    fn from_xy_checked(x: Self::Base, y: Self::Base) -> Result<Self, GroupDecodingError>;
+    fn from_xy_unchecked(x: Self::Base, y: Self::Base) -> Self;
+    fn from_x_and_sign(x: Self::Base, greatest: bool) -> Result<Self, GroupDecodingError>;
+}
+
+pub fn xy_constructor_tests<G>()
+where
+    G: CurveProjective,
+    G::Affine: XyConstructors,
+    <G::Affine as CurveAffine>::Base: PartialOrd,
+{
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    for _ in 0..5 {
+        let p = G::rand(&mut rng).into_affine();
+        assert_eq!(G::Affine::from_xy_checked(p.x(), p.y()).unwrap(), p);
+        assert_eq!(G::Affine::from_xy_unchecked(p.x(), p.y()), p);
+
+        let mut negy = p.y();
+        negy.negate();
+        assert_eq!(G::Affine::from_x_and_sign(p.x(), p.y() > negy).unwrap(), p);
+
+        let mut y = p.y();
+        y.add_assign(&<G::Affine as CurveAffine>::Base::one());
+        assert!(matches!(
+            G::Affine::from_xy_checked(p.x(), y),
+            Err(GroupDecodingError::NotOnCurve)
+        ));
+    }
+
+    let zero = G::Affine::zero();
+    assert!(zero.x().is_zero() && zero.y().is_zero());
+    assert_eq!(G::Affine::from_xy_checked(zero.x(), zero.y()).unwrap(), zero);
+}
+
 fn random_encoding_tests<G: CurveAffine>() {
     let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
 
@@ -457,4 +661,96 @@ fn random_encoding_tests<G: CurveAffine>() {
         let de_compressed = compressed.into_affine().unwrap();
         assert_eq!(de_compressed, r);
     }
+
+    // Batch decoding
+    {
+        let points: Vec<G> = (0..50)
+            .map(|_| G::Projective::rand(&mut rng).into_affine())
+            .collect();
+
+        let mut encoded: Vec<_> = points.iter().map(|p| p.into_uncompressed()).collect();
+        assert_eq!(G::Uncompressed::into_affine_batch(&encoded).unwrap(), points);
+
+        let compressed: Vec<_> = points.iter().map(|p| p.into_compressed()).collect();
+        assert_eq!(G::Compressed::into_affine_batch(&compressed).unwrap(), points);
+
+        // moves the point off the curve
+        let last = encoded[37].as_ref().len() - 1;
+        encoded[37].as_mut()[last] ^= 1;
+        assert!(G::Uncompressed::into_affine_batch(&encoded).is_err());
+    }
+}
+
+pub fn ct_tests<G: CtCurveProjective>()
+where
+    G::Affine: CtCurveAffine,
+    G::Base: CtField,
+    G::Scalar: CtField,
+{
+    use crate::tests::field::ct_field_tests;
+
+    ct_field_tests::<G::Base>();
+    ct_field_tests::<G::Scalar>();
+
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    for _ in 0..5 {
+        let p = G::rand(&mut rng).into_affine();
+        let s = G::Scalar::rand(&mut rng);
+        assert_eq!(p.ct_mul(s).into_projective(), p.mul(s));
+    }
+
+    let p = G::rand(&mut rng).into_affine();
+    let q = G::rand(&mut rng).into_affine();
+    let s = G::Scalar::rand(&mut rng);
+
+    let mut minus_one = G::Scalar::one();
+    minus_one.negate();
+    let mut minus_p = p;
+    minus_p.negate();
+
+    assert_eq!(p.ct_mul(G::Scalar::one()), p);
+    assert_eq!(p.ct_mul(minus_one), minus_p);
+    assert_eq!(p.ct_mul(G::Scalar::zero()), G::Affine::zero());
+    assert_eq!(G::Affine::zero().ct_mul(s), G::Affine::zero());
+
+    // affine points
+    assert_eq!(G::Affine::conditional_select(&p, &q, Choice::from(0)), p);
+    assert_eq!(G::Affine::conditional_select(&p, &q, Choice::from(1)), q);
+    assert!(bool::from(p.ct_eq(&p)));
+    assert!(!bool::from(p.ct_eq(&q)));
+    assert!(!bool::from(p.ct_eq(&G::Affine::zero())));
+    assert!(bool::from(G::Affine::zero().ct_eq(&G::Affine::zero())));
+
+    let mut r = p;
+    r.conditional_negate(Choice::from(0));
+    assert_eq!(r, p);
+    r.conditional_negate(Choice::from(1));
+    assert_eq!(r, minus_p);
+    let mut r = G::Affine::zero();
+    r.conditional_negate(Choice::from(1));
+    assert_eq!(r, G::Affine::zero());
+
+    // projective points, with different z coordinates for the same point
+    let p = p.into_projective();
+    let q = q.into_projective();
+    let mut p2 = p;
+    p2.double();
+    p2.add_assign_mixed(&minus_p);
+
+    assert_eq!(G::conditional_select(&p, &q, Choice::from(0)), p);
+    assert_eq!(G::conditional_select(&p, &q, Choice::from(1)), q);
+    assert!(bool::from(p.ct_eq(&p2)));
+    assert!(!bool::from(p.ct_eq(&q)));
+    assert!(!bool::from(p.ct_eq(&G::zero())));
+    assert!(bool::from(G::zero().ct_eq(&G::zero())));
+
+    assert_eq!(p2.ct_into_affine(), p.into_affine());
+    assert_eq!(G::zero().ct_into_affine(), G::Affine::zero());
+
+    let mut r = p2;
+    r.conditional_negate(Choice::from(1));
+    assert_eq!(r, minus_p.into_projective());
+    r.conditional_negate(Choice::from(0));
+    assert_eq!(r, minus_p.into_projective());
 }
@@ -1,6 +1,9 @@
 use rand::{Rand, SeedableRng, XorShiftRng};
 
-use crate::{CurveAffine, CurveProjective, Engine, Field, PrimeField};
+use crate::{
+    CurveAffine, CurveProjective, Engine, Field, GroupDecodingError, PrimeField, TargetField,
+    TargetGroup,
+};
 
 pub fn engine_tests<E: Engine>() {
     let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
@@ -45,6 +48,8 @@ pub fn engine_tests<E: Engine>() {
 
     random_bilinearity_tests::<E>();
     random_miller_loop_tests::<E>();
+    random_pairing_product_tests::<E>();
+    random_target_group_tests::<E>();
 }
 
 fn random_miller_loop_tests<E: Engine>() {
@@ -124,3 +129,201 @@ fn random_bilinearity_tests<E: Engine>() {
         assert_eq!(acbd, abcd);
     }
 }
+
+fn random_pairing_product_tests<E: Engine>() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    assert!(E::pairing_product_is_one(&[]));
+    assert!(E::batch_pairing_products_are_one(&[], &mut rng));
+
+    for _ in 0..5 {
+        let a = E::G1::rand(&mut rng);
+        let b = E::G2::rand(&mut rng);
+        let s = E::Fr::rand(&mut rng);
+
+        // e(s * a, b) * e(-a, s * b) = 1
+        let mut sa = a;
+        sa.mul_assign(s);
+        let mut na = a;
+        na.negate();
+        let mut sb = b;
+        sb.mul_assign(s);
+
+        let good = [
+            (sa.into_affine(), b.into_affine()),
+            (na.into_affine(), sb.into_affine()),
+        ];
+        let bad = [
+            (sa.into_affine(), b.into_affine()),
+            (a.into_affine(), sb.into_affine()),
+        ];
+
+        let prepare = |terms: &[(E::G1Affine, E::G2Affine)]| -> Vec<_> {
+            terms.iter().map(|(p, q)| (p.prepare(), q.prepare())).collect()
+        };
+        let good_prepared = prepare(&good);
+        let bad_prepared = prepare(&bad);
+
+        assert!(E::pairing_product_is_one(
+            &good_prepared.iter().map(|(p, q)| (p, q)).collect::<Vec<_>>()
+        ));
+        assert!(!E::pairing_product_is_one(
+            &bad_prepared.iter().map(|(p, q)| (p, q)).collect::<Vec<_>>()
+        ));
+
+        assert!(E::batch_pairing_products_are_one(&[&good[..]], &mut rng));
+        assert!(E::batch_pairing_products_are_one(&[&good[..], &good[..], &good[..]], &mut rng));
+        assert!(!E::batch_pairing_products_are_one(&[&bad[..]], &mut rng));
+        assert!(!E::batch_pairing_products_are_one(&[&good[..], &bad[..], &good[..]], &mut rng));
+
+        // Two wrong equations must not cancel each other out.
+        let mut inverse_bad = bad;
+        inverse_bad[0].0.negate();
+        inverse_bad[1].0.negate();
+        assert!(!E::batch_pairing_products_are_one(&[&bad[..], &inverse_bad[..]], &mut rng));
+    }
+}
+
+fn random_target_group_tests<E: Engine>() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    let g = E::Gt::generator();
+    assert!(!g.is_one());
+    assert!(g.is_in_subgroup());
+    assert!(E::Gt::one().is_in_subgroup());
+    assert!(g.pow(E::Fr::char()).is_one());
+    assert_eq!(g.into_fqk(), E::pairing(E::G1Affine::one(), E::G2Affine::one()));
+
+    for _ in 0..10 {
+        let a = E::Fr::rand(&mut rng);
+        let b = E::Fr::rand(&mut rng);
+
+        // Exponentiation agrees with the pairing
+        let ga = g.pow(a.into_repr());
+        assert_eq!(ga.into_fqk(), E::pairing(E::G1Affine::one().mul(a), E::G2Affine::one()));
+        assert!(ga.is_in_subgroup());
+
+        // g^a * g^b = g^(a + b)
+        let mut lhs = ga;
+        lhs.mul_assign(&g.pow(b.into_repr()));
+        let mut ab = a;
+        ab.add_assign(&b);
+        assert_eq!(lhs, g.pow(ab.into_repr()));
+
+        // The unitary inverse is the field inverse
+        let inv = ga.inverse();
+        assert_eq!(inv.into_fqk(), ga.into_fqk().inverse().unwrap());
+        let mut one = ga;
+        one.mul_assign(&inv);
+        assert!(one.is_one());
+
+        let mut sq = ga;
+        sq.square();
+        let mut doubled = a;
+        doubled.double();
+        assert_eq!(sq, g.pow(doubled.into_repr()));
+    }
+
+    for n in 0..5 {
+        let bases: Vec<E::Gt> = (0..n).map(|_| E::Gt::rand(&mut rng)).collect();
+        let exps: Vec<E::Fr> = (0..n).map(|_| E::Fr::rand(&mut rng)).collect();
+
+        let mut expected = E::Gt::one();
+        for (b, e) in bases.iter().zip(exps.iter()) {
+            expected.mul_assign(&b.pow(e.into_repr()));
+        }
+        assert_eq!(E::Gt::multiexp(&bases, &exps), expected);
+    }
+
+    let size = E::Fqk::SUBFIELD_SIZE;
+    let mut elements = vec![E::Gt::one(), g];
+    for _ in 0..5 {
+        elements.push(E::Gt::rand(&mut rng));
+    }
+    for e in elements {
+        let uncompressed = e.into_uncompressed();
+        assert_eq!(uncompressed.len(), 2 * size);
+        assert_eq!(E::Gt::from_uncompressed(&uncompressed).unwrap(), e);
+
+        let compressed = e.into_compressed();
+        assert_eq!(compressed.len(), E::Fqk::COMPRESSED_SIZE);
+        assert_eq!(E::Gt::from_compressed(&compressed).unwrap(), e);
+
+        assert!(matches!(
+            E::Gt::from_uncompressed(&uncompressed[1..]),
+            Err(GroupDecodingError::UnexpectedInformation)
+        ));
+        assert!(matches!(
+            E::Gt::from_compressed(&uncompressed),
+            Err(GroupDecodingError::UnexpectedInformation)
+        ));
+    }
+
+    // Elements outside of the subgroup are rejected
+    let f = E::Fqk::rand(&mut rng);
+    assert!(matches!(E::Gt::from_fqk(f), Err(GroupDecodingError::NotInSubgroup)));
+    let outside = E::Gt::from_fqk_unchecked(f);
+    assert!(!outside.is_in_subgroup());
+    assert!(matches!(
+        E::Gt::from_uncompressed(&outside.into_uncompressed()),
+        Err(GroupDecodingError::NotInSubgroup)
+    ));
+
+    assert!(matches!(
+        E::Gt::from_compressed(&outside.into_compressed()),
+        Err(GroupDecodingError::NotInSubgroup)
+    ));
+
+    // So are non-canonical coordinates
+    assert!(matches!(
+        E::Gt::from_uncompressed(&vec![0xff; 2 * size]),
+        Err(GroupDecodingError::CoordinateDecodingError(..))
+    ));
+    assert!(matches!(
+        E::Gt::from_compressed(&vec![0xff; E::Fqk::COMPRESSED_SIZE]),
+        Err(GroupDecodingError::CoordinateDecodingError(..))
+    ));
+}
+
+pub trait AffineMillerLoop: Engine {
+    type G2PreparedAffine: Clone;
+
+    fn prepare_affine(q: Self::G2Affine) -> Self::G2PreparedAffine;
+    fn is_zero(q: &Self::G2PreparedAffine) -> bool;
+    fn into_prepared(q: Self::G2PreparedAffine) -> <Self::G2Affine as CurveAffine>::Prepared;
+    fn miller_loop_affine(
+        i: &[(&<Self::G1Affine as CurveAffine>::Prepared, &Self::G2PreparedAffine)],
+    ) -> Self::Fqk;
+}
+
+pub fn affine_miller_loop_tests<E: AffineMillerLoop>() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    for _ in 0..5 {
+        let p1 = E::G1::rand(&mut rng).into_affine();
+        let q1 = E::G2::rand(&mut rng).into_affine();
+        let p2 = E::G1::rand(&mut rng).into_affine();
+        let q2 = E::G2::rand(&mut rng).into_affine();
+
+        let mut expected = E::pairing(p1, q1);
+        expected.mul_assign(&E::pairing(p2, q2));
+
+        let (p1, p2) = (p1.prepare(), p2.prepare());
+        let q1 = E::prepare_affine(q1);
+        let q2_affine = E::prepare_affine(q2);
+        let f = E::miller_loop_affine(&[(&p1, &q1), (&p2, &q2_affine)]);
+        assert_eq!(E::final_exponentiation(&f).unwrap(), expected);
+
+        // Both forms can be mixed in Engine::miller_loop.
+        let q1 = E::into_prepared(q1);
+        let q2 = q2.prepare();
+        let f = E::miller_loop(&[(&p1, &q1), (&p2, &q2)]);
+        assert_eq!(E::final_exponentiation(&f).unwrap(), expected);
+    }
+
+    let p = E::G1Affine::one().prepare();
+    let zero = E::prepare_affine(E::G2Affine::zero());
+    assert!(E::is_zero(&zero));
+    assert_eq!(E::miller_loop_affine(&[(&p, &zero)]), E::Fqk::one());
+    assert_eq!(E::miller_loop(&[(&p, &E::into_prepared(zero))]), E::Fqk::one());
+}
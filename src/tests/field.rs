@@ -1,6 +1,8 @@
 use ff::{Field, LegendreSymbol, PrimeField, SqrtField};
 use rand::{Rng, SeedableRng, XorShiftRng};
 
+use crate::ct::{Choice, CtField};
+
 pub fn random_frobenius_tests<F: Field, C: AsRef<[u64]>>(characteristic: C, maxpower: usize) {
     let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
 
@@ -264,3 +266,30 @@ fn random_expansion_tests<F: Field, R: Rng>(rng: &mut R) {
         assert_eq!(t0, t2);
     }
 }
+
+pub fn ct_field_tests<F: CtField>() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    for _ in 0..10 {
+        let a = F::rand(&mut rng);
+        let b = F::rand(&mut rng);
+
+        assert_eq!(F::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(F::conditional_select(&a, &b, Choice::from(1)), b);
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+
+        let mut c = a;
+        c.conditional_negate(Choice::from(0));
+        assert_eq!(c, a);
+        c.conditional_negate(Choice::from(1));
+        c.add_assign(&a);
+        assert!(c.is_zero());
+
+        assert_eq!(a.ct_inverse().unwrap(), a.inverse().unwrap());
+    }
+
+    assert!(bool::from(F::zero().ct_inverse().is_none()));
+    assert_eq!(F::one().ct_inverse().unwrap(), F::one());
+}
@@ -2,3 +2,6 @@ pub mod curve;
 pub mod engine;
 pub mod field;
 pub mod repr;
+pub mod torus;
+#[cfg(feature = "serde")]
+pub mod serialization;
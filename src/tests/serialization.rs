@@ -0,0 +1,102 @@
+use crate::{CurveAffine, CurveProjective, EncodedPoint, Engine, Field, PrimeField, PrimeFieldRepr};
+use rand::{Rand, SeedableRng, XorShiftRng};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+pub fn serde_tests<E: Engine>()
+where
+    E::Fq: Serialize + DeserializeOwned,
+    E::Fr: Serialize + DeserializeOwned,
+    E::Fqe: Serialize + DeserializeOwned,
+    E::Fqk: Serialize + DeserializeOwned,
+    E::G1Affine: Serialize + DeserializeOwned,
+    E::G2Affine: Serialize + DeserializeOwned,
+{
+    random_roundtrip_tests::<E::Fq>();
+    random_roundtrip_tests::<E::Fr>();
+    random_roundtrip_tests::<E::Fqe>();
+    random_roundtrip_tests::<E::Fqk>();
+
+    prime_field_encoding_tests::<E::Fq>();
+    prime_field_encoding_tests::<E::Fr>();
+
+    point_encoding_tests::<E::G1Affine>();
+    point_encoding_tests::<E::G2Affine>();
+}
+
+fn roundtrip<T: Serialize + DeserializeOwned + PartialEq + Debug>(a: &T) {
+    let json = serde_json::to_string(a).unwrap();
+    assert_eq!(*a, serde_json::from_str::<T>(&json).unwrap());
+
+    let bytes = bincode::serialize(a).unwrap();
+    assert_eq!(*a, bincode::deserialize::<T>(&bytes).unwrap());
+}
+
+fn random_roundtrip_tests<F: Field + Serialize + DeserializeOwned>() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    roundtrip(&F::zero());
+    roundtrip(&F::one());
+    for _ in 0..100 {
+        roundtrip(&F::rand(&mut rng));
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn prime_field_encoding_tests<F: PrimeField + Serialize + DeserializeOwned>() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    for _ in 0..100 {
+        let a = F::rand(&mut rng);
+        let mut be = vec![];
+        a.into_repr().write_be(&mut be).unwrap();
+
+        assert_eq!(serde_json::to_string(&a).unwrap(), format!("\"{}\"", to_hex(&be)));
+        assert_eq!(bincode::serialize(&a).unwrap(), be);
+    }
+
+    // Non-canonical encodings are rejected
+    let mut modulus = vec![];
+    F::char().write_be(&mut modulus).unwrap();
+    assert!(serde_json::from_str::<F>(&format!("\"{}\"", to_hex(&modulus))).is_err());
+    assert!(bincode::deserialize::<F>(&modulus).is_err());
+
+    // So are strings of the wrong length or with invalid digits
+    assert!(serde_json::from_str::<F>("\"00\"").is_err());
+    let invalid = format!("\"{}\"", "g".repeat(2 * modulus.len()));
+    assert!(serde_json::from_str::<F>(&invalid).is_err());
+}
+
+fn point_encoding_tests<G: CurveAffine + Serialize + DeserializeOwned>() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    let mut points = vec![G::zero(), G::one()];
+    for _ in 0..20 {
+        points.push(G::Projective::rand(&mut rng).into_affine());
+    }
+
+    for p in points.iter() {
+        roundtrip(p);
+
+        let compressed = p.into_compressed();
+        assert_eq!(
+            serde_json::to_string(p).unwrap(),
+            format!("\"{}\"", to_hex(compressed.as_ref()))
+        );
+        assert_eq!(bincode::serialize(p).unwrap(), compressed.as_ref());
+    }
+}
+
+/// Checks that the compressed encoding of `p`, which must not be in the prime
+/// order subgroup, is rejected by both human-readable and binary formats.
+pub fn serde_rejects_point<G: CurveAffine + Serialize + DeserializeOwned>(p: G) {
+    let compressed = G::Compressed::from_affine(p);
+
+    let json = format!("\"{}\"", to_hex(compressed.as_ref()));
+    assert!(serde_json::from_str::<G>(&json).is_err());
+    assert!(bincode::deserialize::<G>(compressed.as_ref()).is_err());
+}
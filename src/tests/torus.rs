@@ -0,0 +1,142 @@
+use rand::{Rng, SeedableRng, XorShiftRng};
+
+use crate::{Field, GroupDecodingError, SexticTargetField, TargetField, T2, T6};
+
+/// Returns a random element of norm one, `conj(f) / f` for a random `f`.
+fn random_unitary<F: TargetField, R: Rng>(rng: &mut R) -> F {
+    let f = F::rand(rng);
+    let mut res = f;
+    res.conjugate();
+    res.mul_assign(&f.inverse().unwrap());
+    res
+}
+
+/// Returns a random element of the cyclotomic subgroup of a degree 12 field,
+/// `u^(q^2 + 1)` for a random `u` of norm one.
+fn random_cyclotomic<F: SexticTargetField, R: Rng>(rng: &mut R) -> F {
+    let u = random_unitary::<F, R>(rng);
+    let mut res = u;
+    res.frobenius_map(2);
+    res.mul_assign(&u);
+    res
+}
+
+pub fn t2_tests<F: TargetField>() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    assert!(T2::compress(&F::one()).unwrap().is_one());
+    assert_eq!(T2::<F>::one().decompress(), F::one());
+
+    let mut minus_one = F::one();
+    minus_one.negate();
+    let t = T2::compress(&minus_one).unwrap();
+    assert!(t.parameter().unwrap().is_zero());
+    assert_eq!(t.decompress(), minus_one);
+
+    for _ in 0..20 {
+        assert!(T2::compress(&F::rand(&mut rng)).is_none());
+
+        let a = random_unitary::<F, _>(&mut rng);
+        let b = random_unitary::<F, _>(&mut rng);
+        let ta = T2::compress(&a).unwrap();
+        let tb = T2::compress(&b).unwrap();
+        assert_eq!(ta.decompress(), a);
+
+        let mut prod = ta;
+        prod.mul_assign(&tb);
+        let mut expected = a;
+        expected.mul_assign(&b);
+        assert_eq!(prod.decompress(), expected);
+
+        let mut sq = ta;
+        sq.square();
+        let mut expected = a;
+        expected.square();
+        assert_eq!(sq.decompress(), expected);
+
+        let mut inv = ta;
+        inv.inverse();
+        assert_eq!(inv.decompress(), a.inverse().unwrap());
+        inv.mul_assign(&ta);
+        assert!(inv.is_one());
+
+        let exp = [rng.gen::<u64>(), rng.gen::<u64>()];
+        assert_eq!(ta.pow(exp).decompress(), a.pow(exp));
+
+        let mut bytes = vec![0u8; T2::<F>::SIZE];
+        ta.write(&mut bytes);
+        assert_eq!(T2::<F>::read(&bytes).unwrap(), ta);
+    }
+
+    let mut bytes = vec![0u8; T2::<F>::SIZE];
+    T2::<F>::one().write(&mut bytes);
+    assert!(bytes.iter().all(|b| *b == 0));
+    assert!(T2::<F>::read(&bytes).unwrap().is_one());
+
+    assert!(matches!(
+        T2::<F>::read(&vec![0xff; T2::<F>::SIZE]),
+        Err(GroupDecodingError::CoordinateDecodingError(..))
+    ));
+}
+
+pub fn t6_tests<F: SexticTargetField>() {
+    let mut rng = XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+
+    assert_eq!(3 * T6::<F>::SIZE, 2 * F::SUBFIELD_SIZE);
+    assert!(T6::compress(&F::one()).unwrap().is_one());
+    assert_eq!(T6::<F>::one().decompress(), F::one());
+
+    let mut minus_one = F::one();
+    minus_one.negate();
+    assert!(T6::compress(&minus_one).is_none());
+
+    for _ in 0..20 {
+        assert!(T6::compress(&random_unitary::<F, _>(&mut rng)).is_none());
+
+        let a = random_cyclotomic::<F, _>(&mut rng);
+        let b = random_cyclotomic::<F, _>(&mut rng);
+        let ta = T6::compress(&a).unwrap();
+        let tb = T6::compress(&b).unwrap();
+        assert_eq!(ta.decompress(), a);
+
+        let mut prod = ta;
+        prod.mul_assign(&tb);
+        let mut expected = a;
+        expected.mul_assign(&b);
+        assert_eq!(prod.decompress(), expected);
+
+        let mut sq = ta;
+        sq.square();
+        let mut expected = a;
+        expected.square();
+        assert_eq!(sq.decompress(), expected);
+
+        let mut inv = ta;
+        inv.inverse();
+        assert_eq!(inv.decompress(), a.inverse().unwrap());
+        inv.mul_assign(&ta);
+        assert!(inv.is_one());
+
+        let exp = [rng.gen::<u64>(), rng.gen::<u64>()];
+        assert_eq!(ta.pow(exp).decompress(), a.pow(exp));
+
+        let mut bytes = vec![0u8; T6::<F>::SIZE];
+        ta.write(&mut bytes);
+        assert_eq!(T6::<F>::read(&bytes).unwrap(), ta);
+        assert_eq!(F::read_compressed(&bytes).unwrap(), a);
+
+        // g0 = 0 is only allowed for one
+        for b in bytes[F::FQE_SIZE..].iter_mut() {
+            *b = 0;
+        }
+        assert!(matches!(
+            T6::<F>::read(&bytes),
+            Err(GroupDecodingError::NotInSubgroup)
+        ));
+    }
+
+    let mut bytes = vec![0u8; T6::<F>::SIZE];
+    T6::<F>::one().write(&mut bytes);
+    assert!(bytes.iter().all(|b| *b == 0));
+    assert!(T6::<F>::read(&bytes).unwrap().is_one());
+}
@@ -0,0 +1,335 @@
+//! Compression of pairing results on algebraic tori.
+//!
+//! Elements of norm one in `Fqk = F[w] / (w^2 - v)` over its subfield `F`
+//! form the torus `T2` over `F`. Any such element other than one can be
+//! written `(g + w) / (g - w)` for a unique `g = (1 + c0) / c1` in `F`, which
+//! halves its size, and products, squares and inverses can be computed on `g`
+//! directly.
+//!
+//! When moreover `F = E[v] / (v^3 - xi)`, as for BLS12 and BN curves, the
+//! cyclotomic subgroup of `Fqk` is the torus `T6` over `E`, in which `g =
+//! g0 + g1 * v + g2 * v^2` satisfies `3 * g0 * g1 = 1 + 3 * xi * g2^2` with
+//! `g0` non-zero. Keeping only `g0` and `g2` brings the size down to a third.
+
+use super::{Field, GroupDecodingError, PrimeFieldDecodingError, TargetField};
+use crate::BitIterator;
+
+/// Returns the non-residue `v` of `F`.
+fn nonresidue<F: TargetField>() -> F::Subfield {
+    let mut v = F::Subfield::one();
+    F::mul_subfield_by_nonresidue(&mut v);
+    v
+}
+
+/// An element `(g + w) / (g - w)` of the torus `T2` in `F`, represented by
+/// `g`, or one.
+///
+/// The encoding of an element is that of `g`, with one encoded as zero. This
+/// leaves out `-1`, which is not in any subgroup of odd order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct T2<F: TargetField>(Option<F::Subfield>);
+
+impl<F: TargetField> T2<F> {
+    /// The size in bytes of the encoding of an element.
+    pub const SIZE: usize = F::SUBFIELD_SIZE;
+
+    /// Returns one.
+    pub fn one() -> Self {
+        T2(None)
+    }
+
+    /// Determines if this element is one.
+    pub fn is_one(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns the element `(g + w) / (g - w)`.
+    pub fn from_parameter(g: F::Subfield) -> Self {
+        T2(Some(g))
+    }
+
+    /// Returns `g` such that this element is `(g + w) / (g - w)`, or `None`
+    /// if it is one.
+    pub fn parameter(&self) -> Option<F::Subfield> {
+        self.0
+    }
+
+    /// Compresses `f`, returning `None` if it is not of norm one.
+    pub fn compress(f: &F) -> Option<Self> {
+        let mut norm = *f;
+        norm.conjugate();
+        norm.mul_assign(f);
+
+        if norm == F::one() {
+            Some(Self::compress_unchecked(f))
+        } else {
+            None
+        }
+    }
+
+    /// Compresses `f`, which must be of norm one.
+    pub(crate) fn compress_unchecked(f: &F) -> Self {
+        let (mut c0, c1) = f.into_subfield();
+
+        match c1.inverse() {
+            Some(c1_inv) => {
+                c0.add_assign(&F::Subfield::one());
+                c0.mul_assign(&c1_inv);
+                T2(Some(c0))
+            }
+            // f = 1 or f = -1 = (0 + w) / (0 - w)
+            None if c0 == F::Subfield::one() => T2(None),
+            None => T2(Some(F::Subfield::zero())),
+        }
+    }
+
+    /// Returns the element of `Fqk` this represents.
+    pub fn decompress(&self) -> F {
+        let g = match self.0 {
+            Some(g) => g,
+            None => return F::one(),
+        };
+
+        // (g + w) / (g - w) = (g^2 + v + 2g * w) / (g^2 - v), where g^2 - v
+        // is non-zero as v is not a square in F.
+        let mut g2 = g;
+        g2.square();
+        let v = nonresidue::<F>();
+
+        let mut denominator = g2;
+        denominator.sub_assign(&v);
+        let denominator = denominator.inverse().expect("v is not a square");
+
+        let mut c0 = g2;
+        c0.add_assign(&v);
+        c0.mul_assign(&denominator);
+        let mut c1 = g;
+        c1.double();
+        c1.mul_assign(&denominator);
+
+        F::from_subfield(c0, c1)
+    }
+
+    /// Multiplies this element by another.
+    pub fn mul_assign(&mut self, other: &Self) {
+        let (a, b) = match (self.0, other.0) {
+            (Some(a), Some(b)) => (a, b),
+            (None, _) => {
+                *self = *other;
+                return;
+            }
+            (_, None) => return,
+        };
+
+        // (a + w)(b + w) / (a - w)(b - w) = (ab + v + (a + b) w) / (ab + v - (a + b) w)
+        let mut sum = a;
+        sum.add_assign(&b);
+        self.0 = sum.inverse().map(|sum_inv| {
+            let mut res = a;
+            res.mul_assign(&b);
+            res.add_assign(&nonresidue::<F>());
+            res.mul_assign(&sum_inv);
+            res
+        });
+    }
+
+    /// Squares this element.
+    pub fn square(&mut self) {
+        let other = *self;
+        self.mul_assign(&other);
+    }
+
+    /// Replaces this element by its inverse.
+    pub fn inverse(&mut self) {
+        if let Some(ref mut g) = self.0 {
+            g.negate();
+        }
+    }
+
+    /// Raises this element to the power `exp`.
+    pub fn pow<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut res = Self::one();
+        for b in BitIterator::new(exp) {
+            res.square();
+            if b {
+                res.mul_assign(self);
+            }
+        }
+
+        res
+    }
+
+    /// Writes the encoding of this element into `bytes`, which must be `SIZE`
+    /// long.
+    pub fn write(&self, bytes: &mut [u8]) {
+        F::write_subfield(&self.0.unwrap_or_else(F::Subfield::zero), bytes);
+    }
+
+    /// Reads an element from `bytes`, which must be `SIZE` long.
+    pub fn read(bytes: &[u8]) -> Result<Self, GroupDecodingError> {
+        let g = F::read_subfield(bytes).map_err(|e| GroupDecodingError::CoordinateDecodingError("g", e))?;
+
+        Ok(if g.is_zero() { T2(None) } else { T2(Some(g)) })
+    }
+}
+
+/// A target field `Fqk = F[w] / (w^2 - v)` with `F = E[v] / (v^3 - xi)`,
+/// whose cyclotomic subgroup is the torus `T6` over `E`.
+pub trait SexticTargetField: TargetField {
+    /// The field `E`.
+    type Fqe: Field;
+
+    /// The size in bytes of the encoding of an element of `E`.
+    const FQE_SIZE: usize;
+
+    /// Returns `c0 + c1 * v + c2 * v^2`.
+    fn from_fqe(c0: Self::Fqe, c1: Self::Fqe, c2: Self::Fqe) -> Self::Subfield;
+
+    /// Returns the coefficients `(c0, c1, c2)` of `c0 + c1 * v + c2 * v^2`.
+    fn into_fqe(c: &Self::Subfield) -> (Self::Fqe, Self::Fqe, Self::Fqe);
+
+    /// Multiplies an element of `E` by the non-residue `xi`.
+    fn mul_fqe_by_nonresidue(c: &mut Self::Fqe);
+
+    /// Writes the encoding of an element of `E` into `bytes`, which must be
+    /// `FQE_SIZE` long.
+    fn write_fqe(c: &Self::Fqe, bytes: &mut [u8]);
+
+    /// Reads an element of `E` from `bytes`, which must be `FQE_SIZE` long,
+    /// rejecting non-canonical coefficients.
+    fn read_fqe(bytes: &[u8]) -> Result<Self::Fqe, PrimeFieldDecodingError>;
+}
+
+/// An element of the torus `T6` in `F`, represented by the coefficients `g0`
+/// and `g2` of its `T2` parameter, or one.
+///
+/// The encoding of an element is `g2 || g0`, with one encoded as zero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct T6<F: SexticTargetField>(Option<(F::Fqe, F::Fqe)>);
+
+impl<F: SexticTargetField> T6<F> {
+    /// The size in bytes of the encoding of an element.
+    pub const SIZE: usize = 2 * F::FQE_SIZE;
+
+    /// Returns one.
+    pub fn one() -> Self {
+        T6(None)
+    }
+
+    /// Determines if this element is one.
+    pub fn is_one(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Compresses `f`, returning `None` if it is not in the cyclotomic
+    /// subgroup.
+    pub fn compress(f: &F) -> Option<Self> {
+        let t2 = T2::compress(f)?;
+        let res = Self::from_t2(&t2);
+
+        // In T6, g0 is non-zero and determines g1 together with g2.
+        match res.0 {
+            Some((g0, _)) if g0.is_zero() => None,
+            _ if res.into_t2() == t2 => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Compresses `f`, which must be in the cyclotomic subgroup.
+    pub(crate) fn compress_unchecked(f: &F) -> Self {
+        Self::from_t2(&T2::compress_unchecked(f))
+    }
+
+    /// Returns the element of `Fqk` this represents.
+    pub fn decompress(&self) -> F {
+        self.into_t2().decompress()
+    }
+
+    /// Drops the middle coefficient of the parameter of an element of `T6`.
+    fn from_t2(t2: &T2<F>) -> Self {
+        T6(t2.parameter().map(|g| {
+            let (g0, _, g2) = F::into_fqe(&g);
+            (g0, g2)
+        }))
+    }
+
+    /// Returns this element on `T2`, recovering `g1 = (1 + 3 * xi * g2^2) /
+    /// (3 * g0)`.
+    fn into_t2(self) -> T2<F> {
+        let (g0, g2) = match self.0 {
+            Some(g) => g,
+            None => return T2::one(),
+        };
+
+        let mut three = F::Fqe::one();
+        three.double();
+        three.add_assign(&F::Fqe::one());
+
+        let mut g1 = g2;
+        g1.square();
+        F::mul_fqe_by_nonresidue(&mut g1);
+        g1.mul_assign(&three);
+        g1.add_assign(&F::Fqe::one());
+
+        let mut denominator = g0;
+        denominator.mul_assign(&three);
+        g1.mul_assign(&denominator.inverse().expect("g0 is non-zero"));
+
+        T2::from_parameter(F::from_fqe(g0, g1, g2))
+    }
+
+    /// Multiplies this element by another.
+    pub fn mul_assign(&mut self, other: &Self) {
+        let mut res = self.into_t2();
+        res.mul_assign(&other.into_t2());
+        *self = Self::from_t2(&res);
+    }
+
+    /// Squares this element.
+    pub fn square(&mut self) {
+        let mut res = self.into_t2();
+        res.square();
+        *self = Self::from_t2(&res);
+    }
+
+    /// Replaces this element by its inverse.
+    pub fn inverse(&mut self) {
+        if let Some((ref mut g0, ref mut g2)) = self.0 {
+            g0.negate();
+            g2.negate();
+        }
+    }
+
+    /// Raises this element to the power `exp`.
+    pub fn pow<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        Self::from_t2(&self.into_t2().pow(exp))
+    }
+
+    /// Writes the encoding of this element into `bytes`, which must be `SIZE`
+    /// long.
+    pub fn write(&self, bytes: &mut [u8]) {
+        let (g0, g2) = self.0.unwrap_or_else(|| (F::Fqe::zero(), F::Fqe::zero()));
+
+        F::write_fqe(&g2, &mut bytes[..F::FQE_SIZE]);
+        F::write_fqe(&g0, &mut bytes[F::FQE_SIZE..]);
+    }
+
+    /// Reads an element from `bytes`, which must be `SIZE` long.
+    pub fn read(bytes: &[u8]) -> Result<Self, GroupDecodingError> {
+        let g2 = F::read_fqe(&bytes[..F::FQE_SIZE])
+            .map_err(|e| GroupDecodingError::CoordinateDecodingError("g2", e))?;
+        let g0 = F::read_fqe(&bytes[F::FQE_SIZE..])
+            .map_err(|e| GroupDecodingError::CoordinateDecodingError("g0", e))?;
+
+        if g0.is_zero() {
+            if g2.is_zero() {
+                Ok(T6(None))
+            } else {
+                // 1 + 3 * xi * g2^2 = 0 has no solution, as xi is not a square.
+                Err(GroupDecodingError::NotInSubgroup)
+            }
+        } else {
+            Ok(T6(Some((g0, g2))))
+        }
+    }
+}
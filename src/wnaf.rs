@@ -42,6 +42,43 @@ pub(crate) fn wnaf_form<S: PrimeFieldRepr>(wnaf: &mut Vec<i64>, mut c: S, window
     }
 }
 
+/// Returns the non-adjacent form of an exponent given as little-endian limbs,
+/// least significant digit first.
+pub(crate) fn naf_form(exp: &[u64]) -> Vec<i8> {
+    let mut c = exp.to_vec();
+    c.push(0);
+
+    let mut naf = vec![];
+    while c.iter().any(|limb| *limb != 0) {
+        let digit = if c[0] & 1 == 0 {
+            0
+        } else if c[0] & 3 == 1 {
+            c[0] -= 1;
+            1
+        } else {
+            for limb in c.iter_mut() {
+                let (sum, carry) = limb.overflowing_add(1);
+                *limb = sum;
+                if !carry {
+                    break;
+                }
+            }
+            -1
+        };
+
+        naf.push(digit);
+
+        let mut high = 0;
+        for limb in c.iter_mut().rev() {
+            let low = *limb & 1;
+            *limb = (*limb >> 1) | (high << 63);
+            high = low;
+        }
+    }
+
+    naf
+}
+
 /// Performs w-NAF exponentiation with the provided window table and w-NAF form scalar.
 ///
 /// This function must be provided a `table` and `wnaf` that were constructed with